@@ -0,0 +1,362 @@
+//! Group actions on sets.
+//!
+//! A left action of `G` on `X` is a map `G × X -> X, (g, x) ↦ g·x` with `1·x = x` and
+//! `(gh)·x = g·(h·x)`. A right action `X × G -> X` satisfies `x·1 = x` and `x·(gh) = (x·g)·h`
+//! instead.
+//!
+//! Since a group can act on the same set in several ways, an action is a value of its own, just
+//! like a [`Homomorphism`](crate::structures::Homomorphism). Any closure `Fn(&G, &X) -> X` can be
+//! used as a left or right action.
+//!
+//! Left actions of finite groups come with orbits, stabilizers, [Schreier vectors](SchreierVector)
+//! and orbit counting through Burnside's lemma. Colourings can also be counted with the
+//! [cycle index](CycleIndex) of the group.
+//!
+//! # Example
+//! ```rust
+//! use abstract_algebra::perm;
+//! use abstract_algebra::actions::{Conjugation, LeftAction};
+//! use abstract_algebra::ops::Identity;
+//! use abstract_algebra::primitives::{OnEntries, OnPoints};
+//!
+//! let g = perm![3; (1 2 3)];
+//! assert_eq!(OnPoints.act(&g, &3), 1);
+//! assert_eq!(OnEntries.act(&g, &['a', 'b', 'c']), ['c', 'a', 'b']);
+//! assert_eq!(Conjugation.act(&g, &perm![(1 2)]), perm![(2 3)]);
+//! ```
+mod orbit;
+mod polya;
+
+pub use orbit::*;
+pub use polya::*;
+
+use std::marker::PhantomData;
+
+use crate::{
+    ops::{Multiplication, OperationKind},
+    structures::{Group, Subgroup},
+};
+
+/// A left action `(g, x) ↦ g·x` of `G` on `X`
+pub trait LeftAction<G, X, Op = Multiplication>
+where
+    G: Group<Op>,
+    Op: OperationKind,
+{
+    fn act(&self, g: &G, x: &X) -> X;
+
+    /// Verifies the action axioms for the subgroup generated by `generators` acting on `points`
+    /// by checking `1·p = p` and `(xg)·p = x·(g·p)` for every element `x`, generator `g` and
+    /// point `p`
+    ///
+    /// # Note
+    /// See [`Subgroup::generated_by`] for infinite groups
+    fn is_action_on(&self, generators: &[G], points: &[X]) -> bool
+    where
+        X: PartialEq,
+    {
+        let group = Subgroup::<G, Op>::generated_by(generators.to_vec());
+        let id = G::id();
+        points.iter().all(|p| self.act(&id, p) == *p)
+            && group.elements().iter().all(|x| {
+                generators.iter().all(|g| {
+                    points
+                        .iter()
+                        .all(|p| self.act(&x.op(g), p) == self.act(x, &self.act(g, p)))
+                })
+            })
+    }
+
+    /// The orbit `G·x` of `x` under `group`, starting with `x`
+    fn orbit(&self, group: &Subgroup<G, Op>, x: &X) -> Vec<X>
+    where
+        X: Clone + PartialEq,
+    {
+        self.schreier_vector(group, x).points().to_vec()
+    }
+
+    /// Partitions `points`, which have to be closed under the action, into the orbits of `group`
+    fn orbits(&self, group: &Subgroup<G, Op>, points: &[X]) -> Vec<Vec<X>>
+    where
+        X: Clone + PartialEq,
+    {
+        let mut orbits: Vec<Vec<X>> = Vec::new();
+        for x in points {
+            if !orbits.iter().any(|orbit| orbit.contains(x)) {
+                orbits.push(self.orbit(group, x));
+            }
+        }
+        orbits
+    }
+
+    /// The stabilizer `{ g in group | g·x = x }` of `x`
+    fn stabilizer(&self, group: &Subgroup<G, Op>, x: &X) -> Subgroup<G, Op>
+    where
+        X: PartialEq,
+    {
+        Subgroup::from_elements(
+            group
+                .elements()
+                .iter()
+                .filter(|g| self.act(g, x) == *x)
+                .cloned(),
+        )
+    }
+
+    /// The Schreier vector of the orbit of `x`, which stores for every point of the orbit an
+    /// element of `group` mapping `x` to it
+    fn schreier_vector(&self, group: &Subgroup<G, Op>, x: &X) -> SchreierVector<G, X, Op>
+    where
+        X: Clone + PartialEq,
+    {
+        SchreierVector::new(self, group.generators(), x)
+    }
+
+    /// Checks the orbit-stabilizer theorem `|G·x| |G_x| = |G|` for `x`
+    fn satisfies_orbit_stabilizer(&self, group: &Subgroup<G, Op>, x: &X) -> bool
+    where
+        X: Clone + PartialEq,
+    {
+        self.orbit(group, x).len() * self.stabilizer(group, x).order() == group.order()
+    }
+
+    /// The points among `points` fixed by `g`
+    fn fixed_points(&self, g: &G, points: &[X]) -> Vec<X>
+    where
+        X: Clone + PartialEq,
+    {
+        points
+            .iter()
+            .filter(|x| self.act(g, x) == **x)
+            .cloned()
+            .collect()
+    }
+
+    /// The number of orbits of `group` on `points`, which have to be closed under the action,
+    /// computed with Burnside's lemma as the average number of fixed points
+    ///
+    /// # Example
+    /// ```rust
+    /// use abstract_algebra::actions::LeftAction;
+    /// use abstract_algebra::primitives::{DihedralElement, OnVertices};
+    /// use abstract_algebra::structures::Subgroup;
+    ///
+    /// // Necklaces with 6 beads in 2 colours, up to rotations and reflections
+    /// let necklaces: Vec<[bool; 6]> = (0..64_usize)
+    ///     .map(|k| core::array::from_fn(|i| k >> i & 1 == 1))
+    ///     .collect();
+    /// let d6 = Subgroup::<DihedralElement<6>>::whole();
+    /// assert_eq!(OnVertices.count_orbits(&d6, &necklaces), 13);
+    /// ```
+    fn count_orbits(&self, group: &Subgroup<G, Op>, points: &[X]) -> usize
+    where
+        X: PartialEq,
+    {
+        let fixed: usize = group
+            .elements()
+            .iter()
+            .map(|g| points.iter().filter(|x| self.act(g, x) == **x).count())
+            .sum();
+        fixed / group.order()
+    }
+}
+
+/// A right action `(x, g) ↦ x·g` of `G` on `X`
+pub trait RightAction<G, X, Op = Multiplication>
+where
+    G: Group<Op>,
+    Op: OperationKind,
+{
+    fn act(&self, x: &X, g: &G) -> X;
+
+    /// Verifies the action axioms for the subgroup generated by `generators` acting on `points`
+    /// by checking `p·1 = p` and `p·(xg) = (p·x)·g` for every element `x`, generator `g` and
+    /// point `p`
+    ///
+    /// # Note
+    /// See [`Subgroup::generated_by`] for infinite groups
+    fn is_action_on(&self, generators: &[G], points: &[X]) -> bool
+    where
+        X: PartialEq,
+    {
+        let group = Subgroup::<G, Op>::generated_by(generators.to_vec());
+        let id = G::id();
+        points.iter().all(|p| self.act(p, &id) == *p)
+            && group.elements().iter().all(|x| {
+                generators.iter().all(|g| {
+                    points
+                        .iter()
+                        .all(|p| self.act(p, &x.op(g)) == self.act(&self.act(p, x), g))
+                })
+            })
+    }
+}
+
+impl<G, X, Op, F> LeftAction<G, X, Op> for F
+where
+    G: Group<Op>,
+    Op: OperationKind,
+    F: Fn(&G, &X) -> X,
+{
+    fn act(&self, g: &G, x: &X) -> X {
+        self(g, x)
+    }
+}
+
+impl<G, X, Op, F> RightAction<G, X, Op> for F
+where
+    G: Group<Op>,
+    Op: OperationKind,
+    F: Fn(&X, &G) -> X,
+{
+    fn act(&self, x: &X, g: &G) -> X {
+        self(x, g)
+    }
+}
+
+/// The right action `x·g = g^-1·x` obtained from a left action, and vice versa
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Opposite<A, Op = Multiplication> {
+    action: A,
+    _op: PhantomData<Op>,
+}
+
+impl<A, Op: OperationKind> Opposite<A, Op> {
+    pub fn new(action: A) -> Self {
+        Self {
+            action,
+            _op: PhantomData,
+        }
+    }
+}
+
+impl<G, X, Op, A> RightAction<G, X, Op> for Opposite<A, Op>
+where
+    G: Group<Op>,
+    Op: OperationKind,
+    A: LeftAction<G, X, Op>,
+{
+    fn act(&self, x: &X, g: &G) -> X {
+        self.action.act(&g.inv(), x)
+    }
+}
+
+impl<G, X, Op, A> LeftAction<G, X, Op> for Opposite<A, Op>
+where
+    G: Group<Op>,
+    Op: OperationKind,
+    A: RightAction<G, X, Op>,
+{
+    fn act(&self, g: &G, x: &X) -> X {
+        self.action.act(x, &g.inv())
+    }
+}
+
+/// A group acting on itself by multiplication, i.e. `g·x = gx` from the left and `x·g = xg` from
+/// the right
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Translation;
+
+impl<G: Group<Op>, Op: OperationKind> LeftAction<G, G, Op> for Translation {
+    fn act(&self, g: &G, x: &G) -> G {
+        g.op(x)
+    }
+}
+
+impl<G: Group<Op>, Op: OperationKind> RightAction<G, G, Op> for Translation {
+    fn act(&self, x: &G, g: &G) -> G {
+        x.op(g)
+    }
+}
+
+/// A group acting on itself by conjugation, i.e. `g·x = gxg^-1` from the left and
+/// `x·g = g^-1xg` from the right
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conjugation;
+
+impl<G: Group<Op>, Op: OperationKind> LeftAction<G, G, Op> for Conjugation {
+    fn act(&self, g: &G, x: &G) -> G {
+        x.conjugate(g)
+    }
+}
+
+impl<G: Group<Op>, Op: OperationKind> RightAction<G, G, Op> for Conjugation {
+    fn act(&self, x: &G, g: &G) -> G {
+        x.conjugate(&g.inv())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::{Addition, BinOp, Identity, Invertible},
+        perm,
+        primitives::{CyclicNumber, DihedralElement, Permutation},
+        structures::FiniteGroup,
+    };
+
+    use super::*;
+
+    #[test]
+    fn group_acting_on_itself() {
+        let elements: Vec<Permutation<3>> = Permutation::elements().collect();
+        let generators = [perm![3; (1 2)], perm![3; (1 2 3)]];
+        assert!(LeftAction::is_action_on(
+            &Translation,
+            &generators,
+            &elements
+        ));
+        assert!(RightAction::is_action_on(
+            &Translation,
+            &generators,
+            &elements
+        ));
+        assert!(LeftAction::is_action_on(
+            &Conjugation,
+            &generators,
+            &elements
+        ));
+        assert!(RightAction::is_action_on(
+            &Conjugation,
+            &generators,
+            &elements
+        ));
+        assert!(RightAction::is_action_on(
+            &Opposite::new(Conjugation),
+            &generators,
+            &elements
+        ));
+
+        let g = perm![3; (1 2 3)];
+        let x = perm![3; (1 2)];
+        assert_eq!(LeftAction::act(&Conjugation, &g, &x), g.op(&x).op(&g.inv()));
+        assert_eq!(
+            RightAction::act(&Opposite::new(Conjugation), &x, &g),
+            RightAction::act(&Conjugation, &x, &g)
+        );
+    }
+
+    #[test]
+    fn closures() {
+        let shift = |g: &CyclicNumber<5>, x: &CyclicNumber<5>| BinOp::<Addition>::op(g, x);
+        let points: Vec<CyclicNumber<5>> = CyclicNumber::elements().collect();
+        assert!(LeftAction::<_, _, Addition>::is_action_on(
+            &shift,
+            &[1.into()],
+            &points
+        ));
+
+        // Multiplying from the left is not a right action of a non-abelian group
+        let left = |x: &DihedralElement<3>, g: &DihedralElement<3>| g.op(x);
+        let generators = [
+            DihedralElement::new(1, false),
+            DihedralElement::new(0, true),
+        ];
+        let points: Vec<DihedralElement<3>> = DihedralElement::elements().collect();
+        assert!(!RightAction::is_action_on(&left, &generators, &points));
+        assert_eq!(
+            RightAction::act(&left, &DihedralElement::id(), &generators[1]),
+            generators[1]
+        );
+    }
+}
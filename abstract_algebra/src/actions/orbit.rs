@@ -0,0 +1,170 @@
+use std::marker::PhantomData;
+
+use crate::{
+    actions::LeftAction,
+    ops::{Multiplication, OperationKind},
+    structures::Group,
+    utils::orbit,
+};
+
+/// The orbit of a point `root` under the group generated by some generators, stored as a tree
+/// whose edges are labelled by generators.
+///
+/// Every point `p` of the orbit other than `root` has an edge `(k, q)` with `p = g_k·q` for an
+/// earlier point `q`, so following the edges yields a transversal, i.e. an element mapping
+/// `root` to `p`.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::perm;
+/// use abstract_algebra::actions::LeftAction;
+/// use abstract_algebra::ops::Identity;
+/// use abstract_algebra::primitives::{OnPoints, Permutation};
+/// use abstract_algebra::structures::Subgroup;
+///
+/// let group = Subgroup::<Permutation<5>>::generated_by([perm![(1 2 3)], perm![(3 4)]]);
+/// let vector = OnPoints.schreier_vector(&group, &1);
+/// assert_eq!(vector.points(), &[1, 2, 3, 4]);
+/// let u = vector.representative(&4).unwrap();
+/// assert_eq!(OnPoints.act(&u, &1), 4);
+/// assert_eq!(vector.representative(&5), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SchreierVector<G: Group<Op>, X, Op: OperationKind = Multiplication> {
+    generators: Vec<G>,
+    points: Vec<X>,
+    edges: Vec<Option<(usize, usize)>>,
+    _op: PhantomData<Op>,
+}
+
+impl<G: Group<Op>, X: Clone + PartialEq, Op: OperationKind> SchreierVector<G, X, Op> {
+    /// Computes the orbit of `root` under the group generated by `generators` breadth-first
+    ///
+    /// # Note
+    /// See [`Subgroup::generated_by`](crate::structures::Subgroup::generated_by) for infinite
+    /// orbits
+    pub fn new<A>(action: &A, generators: &[G], root: &X) -> Self
+    where
+        A: LeftAction<G, X, Op> + ?Sized,
+    {
+        let (points, edges) = orbit(root.clone(), generators.len(), |k, x| {
+            action.act(&generators[k], x)
+        });
+        Self {
+            generators: generators.to_vec(),
+            points,
+            edges,
+            _op: PhantomData,
+        }
+    }
+
+    pub fn root(&self) -> &X {
+        &self.points[0]
+    }
+
+    pub fn generators(&self) -> &[G] {
+        &self.generators
+    }
+
+    /// The points of the orbit in breadth-first order, starting with the root
+    pub fn points(&self) -> &[X] {
+        &self.points
+    }
+
+    /// The edges as `(generator, parent)` indices into [`generators`](Self::generators) and
+    /// [`points`](Self::points), with `None` for the root
+    pub fn edges(&self) -> &[Option<(usize, usize)>] {
+        &self.edges
+    }
+
+    pub fn contains(&self, x: &X) -> bool {
+        self.points.contains(x)
+    }
+
+    /// An element `u` with `u·root = x`, or `None` if `x` is not in the orbit
+    pub fn representative(&self, x: &X) -> Option<G> {
+        let mut i = self.points.iter().position(|p| p == x)?;
+        let mut u = G::id();
+        while let Some((k, parent)) = self.edges[i] {
+            u = u.op(&self.generators[k]);
+            i = parent;
+        }
+        Some(u)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        actions::{Conjugation, Translation},
+        ops::Identity,
+        perm,
+        primitives::{DihedralElement, OnEntries, OnPoints, OnVertices, Permutation},
+        structures::{FiniteGroup, Subgroup},
+    };
+
+    use super::*;
+
+    #[test]
+    fn orbits_and_stabilizers() {
+        let group = Subgroup::<Permutation<6>>::generated_by([perm![(1 2 3)], perm![(4 5)]]);
+        let points: Vec<usize> = (1..=6).collect();
+        assert_eq!(
+            OnPoints.orbits(&group, &points),
+            vec![vec![1, 2, 3], vec![4, 5], vec![6]]
+        );
+        assert_eq!(OnPoints.count_orbits(&group, &points), 3);
+        for x in &points {
+            assert!(OnPoints.satisfies_orbit_stabilizer(&group, x));
+        }
+        let stabilizer = OnPoints.stabilizer(&group, &4);
+        assert_eq!(stabilizer.order(), 3);
+        assert!(stabilizer.contains(&perm![(1 3 2)]));
+
+        let vector = OnPoints.schreier_vector(&group, &2);
+        for x in vector.points() {
+            let u = vector.representative(x).unwrap();
+            assert!(group.contains(&u));
+            assert_eq!(OnPoints.act(&u, vector.root()), *x);
+        }
+    }
+
+    #[test]
+    fn conjugacy_classes_as_orbits() {
+        let group = Subgroup::<Permutation<4>>::whole();
+        let sizes: Vec<usize> = LeftAction::orbits(&Conjugation, &group, group.elements())
+            .iter()
+            .map(Vec::len)
+            .collect();
+        let mut sorted = sizes.clone();
+        sorted.sort();
+        assert_eq!(sorted, Permutation::<4>::class_equation());
+        // The regular action is transitive with trivial stabilizers
+        let id = Permutation::id();
+        assert_eq!(LeftAction::orbit(&Translation, &group, &id).len(), 24);
+        assert!(LeftAction::stabilizer(&Translation, &group, &id).is_trivial());
+    }
+
+    #[test]
+    fn burnside() {
+        // Colourings of the vertices of a square with 3 colours
+        let colorings: Vec<[u8; 4]> = (0..81_u32)
+            .map(|k| core::array::from_fn(|i| (k / 3_u32.pow(i as u32) % 3) as u8))
+            .collect();
+        let d4 = Subgroup::<DihedralElement<4>>::whole();
+        let c4 = Subgroup::generated_by([DihedralElement::<4>::new(1, false)]);
+        assert_eq!(OnVertices.count_orbits(&d4, &colorings), 21);
+        assert_eq!(OnVertices.count_orbits(&c4, &colorings), 24);
+        assert_eq!(
+            OnVertices.orbits(&d4, &colorings).len(),
+            OnVertices.count_orbits(&d4, &colorings)
+        );
+        // Up to arbitrary permutations only the number of vertices of each colour matters
+        let s4 = Subgroup::<Permutation<4>>::whole();
+        assert_eq!(OnEntries.count_orbits(&s4, &colorings), 15);
+        assert_eq!(
+            OnEntries.fixed_points(&perm![(1 2 3 4)], &colorings).len(),
+            3
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    actions::LeftAction,
+    ops::OperationKind,
+    primitives::Permutation,
+    structures::{Group, Subgroup},
+};
+
+/// The cycle index `Z(G) = 1/|G| Σ_g a_1^{c_1(g)} ... a_n^{c_n(g)}` of a group acting on `n`
+/// points, where `c_k(g)` is the number of cycles of length `k` of `g`.
+///
+/// By Pólya's enumeration theorem, substituting `a_k = Σ_c w_c^k` counts the colourings of the
+/// points up to the action of the group, weighted by the colour weights `w_c`.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::perm;
+/// use abstract_algebra::actions::CycleIndex;
+/// use abstract_algebra::ops::Identity;
+/// use abstract_algebra::primitives::Permutation;
+/// use abstract_algebra::structures::Subgroup;
+///
+/// // The rotations of a cube acting on its faces, numbered top, bottom, front, back, left, right
+/// let rotations = Subgroup::<Permutation<6>>::generated_by([perm![(3 6 4 5)], perm![(1 6 2 5)]]);
+/// let index = CycleIndex::of(&rotations);
+/// assert_eq!(
+///     index.to_string(),
+///     "(a1^6 + 3 a1^2 a2^2 + 6 a1^2 a4 + 6 a2^3 + 8 a3^2) / 24"
+/// );
+/// assert_eq!(index.count_colorings(3), 57);
+/// // Two faces of each colour
+/// assert_eq!(index.count_with_content(&[2, 2, 2]), 6);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleIndex {
+    order: usize,
+    degree: usize,
+    terms: Vec<(Vec<usize>, usize)>,
+}
+
+impl CycleIndex {
+    /// The cycle index of a group of permutations acting on `1..=N`
+    pub fn of<const N: usize>(group: &Subgroup<Permutation<N>>) -> Self {
+        Self::from_cycle_types(N, group.elements().iter().map(Permutation::cycle_type))
+    }
+
+    /// The cycle index of `group` acting on `points`, which have to be closed under the action
+    ///
+    /// # Panics
+    /// if some point is mapped outside of `points`
+    pub fn of_action<G, X, Op, A>(action: &A, group: &Subgroup<G, Op>, points: &[X]) -> Self
+    where
+        G: Group<Op>,
+        X: PartialEq,
+        Op: OperationKind,
+        A: LeftAction<G, X, Op>,
+    {
+        let cycle_types = group.elements().iter().map(|g| {
+            cycle_lengths(points.len(), |i| {
+                let image = action.act(g, &points[i]);
+                points
+                    .iter()
+                    .position(|p| *p == image)
+                    .expect("the points have to be closed under the action")
+            })
+        });
+        Self::from_cycle_types(points.len(), cycle_types)
+    }
+
+    fn from_cycle_types(degree: usize, cycle_types: impl Iterator<Item = Vec<usize>>) -> Self {
+        let mut order = 0;
+        let mut terms: Vec<(Vec<usize>, usize)> = Vec::new();
+        for lengths in cycle_types {
+            let mut exponents = vec![0; degree];
+            for k in lengths {
+                exponents[k - 1] += 1;
+            }
+            match terms.iter_mut().find(|(e, _)| *e == exponents) {
+                Some((_, count)) => *count += 1,
+                None => terms.push((exponents, 1)),
+            }
+            order += 1;
+        }
+        terms.sort_by(|a, b| b.0.cmp(&a.0));
+        Self {
+            order,
+            degree,
+            terms,
+        }
+    }
+
+    /// The order of the group
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// The number of points acted on
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// The monomials as pairs of the exponents `[c_1, ..., c_n]` and the number of group
+    /// elements with that cycle type
+    pub fn terms(&self) -> &[(Vec<usize>, usize)] {
+        &self.terms
+    }
+
+    /// Evaluates the cycle index at `a_k = value(k)`
+    ///
+    /// # Note
+    /// The result is rounded down if the substitution does not yield an integer
+    pub fn substitute(&self, value: impl Fn(usize) -> usize) -> usize {
+        let total: usize = self
+            .terms
+            .iter()
+            .map(|(exponents, count)| {
+                exponents
+                    .iter()
+                    .enumerate()
+                    .map(|(k, &e)| value(k + 1).pow(e as u32))
+                    .product::<usize>()
+                    * count
+            })
+            .sum();
+        total / self.order
+    }
+
+    /// The number of colourings with `colors` colours up to the action of the group
+    pub fn count_colorings(&self, colors: usize) -> usize {
+        self.substitute(|_| colors)
+    }
+
+    /// The sum over all colourings up to the action of the group of the products of the weights
+    /// of the colours used, where every point contributes the weight of its colour
+    pub fn count_weighted(&self, weights: &[usize]) -> usize {
+        self.substitute(|k| weights.iter().map(|w| w.pow(k as u32)).sum())
+    }
+
+    /// The number of colourings up to the action of the group which use the `c`-th colour on
+    /// exactly `content[c]` points, i.e. a coefficient of the pattern inventory
+    pub fn count_with_content(&self, content: &[usize]) -> usize {
+        if content.iter().sum::<usize>() != self.degree {
+            return 0;
+        }
+        let total: usize = self
+            .terms
+            .iter()
+            .map(|(exponents, count)| {
+                // Distribute the cycles among the colours, keeping track of the number of
+                // points still to be coloured with each colour
+                let mut ways = HashMap::from([(content.to_vec(), 1)]);
+                for (k, &e) in exponents.iter().enumerate() {
+                    for _ in 0..e {
+                        let mut next = HashMap::new();
+                        for (remaining, n) in ways {
+                            for c in 0..remaining.len() {
+                                if remaining[c] > k {
+                                    let mut remaining = remaining.clone();
+                                    remaining[c] -= k + 1;
+                                    *next.entry(remaining).or_insert(0) += n;
+                                }
+                            }
+                        }
+                        ways = next;
+                    }
+                }
+                ways.values().sum::<usize>() * count
+            })
+            .sum();
+        total / self.order
+    }
+}
+
+/// Renders the cycle index as e.g. `(a1^4 + 2 a1^2 a2 + 3 a2^2 + 2 a4) / 8`
+impl fmt::Display for CycleIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms: Vec<String> = self
+            .terms
+            .iter()
+            .map(|(exponents, count)| {
+                let mut factors: Vec<String> = Vec::new();
+                if *count > 1 {
+                    factors.push(count.to_string());
+                }
+                for (k, &e) in exponents.iter().enumerate() {
+                    match e {
+                        0 => {}
+                        1 => factors.push(format!("a{}", k + 1)),
+                        e => factors.push(format!("a{}^{e}", k + 1)),
+                    }
+                }
+                if factors.is_empty() {
+                    factors.push("1".to_string());
+                }
+                factors.join(" ")
+            })
+            .collect();
+        write!(f, "({}) / {}", terms.join(" + "), self.order)
+    }
+}
+
+/// The cycle lengths of the permutation `i -> image(i)` of `0..degree`
+fn cycle_lengths(degree: usize, image: impl Fn(usize) -> usize) -> Vec<usize> {
+    let mut seen = vec![false; degree];
+    let mut lengths = Vec::new();
+    for start in 0..degree {
+        let mut length = 0;
+        let mut i = start;
+        while !seen[i] {
+            seen[i] = true;
+            i = image(i);
+            length += 1;
+        }
+        if length > 0 {
+            lengths.push(length);
+        }
+    }
+    lengths
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::Identity,
+        perm,
+        primitives::{DihedralElement, OnVertices},
+    };
+
+    use super::*;
+
+    #[test]
+    fn necklaces_and_bracelets() {
+        let rotations = Subgroup::<Permutation<6>>::generated_by([perm![(1 2 3 4 5 6)]]);
+        let necklaces = CycleIndex::of(&rotations);
+        assert_eq!(necklaces.order(), 6);
+        assert_eq!(necklaces.count_colorings(2), 14);
+        assert_eq!(necklaces.count_colorings(3), 130);
+
+        let d6 = Subgroup::<DihedralElement<6>>::whole();
+        let bracelets = CycleIndex::of_action(&OnVertices, &d6, &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(bracelets.count_colorings(2), 13);
+        assert_eq!(bracelets.count_colorings(3), 92);
+        // Bracelets with 3 black and 3 white beads
+        assert_eq!(bracelets.count_with_content(&[3, 3]), 3);
+        assert_eq!(bracelets.count_with_content(&[3, 2]), 0);
+        // The pattern inventory sums up to all colourings
+        let total: usize = (0..=6)
+            .map(|k| bracelets.count_with_content(&[k, 6 - k]))
+            .sum();
+        assert_eq!(total, 13);
+    }
+
+    #[test]
+    fn square() {
+        let d4 = Subgroup::<DihedralElement<4>>::whole();
+        let index = CycleIndex::of_action(&OnVertices, &d4, &[1, 2, 3, 4]);
+        assert_eq!(index.to_string(), "(a1^4 + 2 a1^2 a2 + 3 a2^2 + 2 a4) / 8");
+        assert_eq!(index.count_colorings(3), 21);
+        // Agrees with Burnside's lemma on the colourings themselves
+        let colorings: Vec<[u8; 4]> = (0..81_u32)
+            .map(|k| core::array::from_fn(|i| (k / 3_u32.pow(i as u32) % 3) as u8))
+            .collect();
+        assert_eq!(OnVertices.count_orbits(&d4, &colorings), 21);
+        // Weighting a colour with 0 forbids it
+        assert_eq!(index.count_weighted(&[1, 1, 0]), index.count_colorings(2));
+        assert_eq!(
+            CycleIndex::of(&Subgroup::<Permutation<4>>::trivial()).count_colorings(3),
+            81
+        );
+    }
+}
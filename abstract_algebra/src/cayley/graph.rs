@@ -0,0 +1,145 @@
+use std::marker::PhantomData;
+
+use crate::{
+    ops::{Multiplication, OperationKind},
+    structures::{Group, Subgroup},
+};
+
+/// Colours assigned to the generators, cycled through if there are more generators
+const COLORS: [&str; 8] = [
+    "red",
+    "blue",
+    "darkgreen",
+    "orange",
+    "purple",
+    "brown",
+    "deeppink",
+    "cyan4",
+];
+
+/// The Cayley graph of the group generated by a set of generators.
+///
+/// The vertices are the elements of the generated group and there is an edge `x -> xg` for every
+/// element `x` and every generator `g`.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::cayley::CayleyGraph;
+/// use abstract_algebra::primitives::DihedralElement;
+///
+/// let r = DihedralElement::<4>::new(1, false);
+/// let s = DihedralElement::<4>::new(0, true);
+/// let graph: CayleyGraph<_> = CayleyGraph::new([r, s]);
+/// assert_eq!(graph.vertices().len(), 8);
+/// assert_eq!(graph.edges().len(), 16);
+///
+/// let dot = graph.to_dot(|x| format!("{x:?}"));
+/// assert!(dot.starts_with("digraph cayley {"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CayleyGraph<G: Group<Op>, Op: OperationKind = Multiplication> {
+    generators: Vec<G>,
+    vertices: Vec<G>,
+    edges: Vec<(usize, usize, usize)>,
+    _op: PhantomData<Op>,
+}
+
+impl<G: Group<Op>, Op: OperationKind> CayleyGraph<G, Op> {
+    /// # Note
+    /// See [`Subgroup::generated_by`] for infinite groups
+    pub fn new(generators: impl IntoIterator<Item = G>) -> Self {
+        let subgroup = Subgroup::<G, Op>::generated_by(generators);
+        let vertices = subgroup.elements().to_vec();
+        let generators = subgroup.generators().to_vec();
+        let edges = vertices
+            .iter()
+            .enumerate()
+            .flat_map(|(i, x)| {
+                let vertices = &vertices;
+                generators.iter().enumerate().map(move |(k, g)| {
+                    let y = x.op(g);
+                    let j = vertices.iter().position(|v| *v == y).unwrap();
+                    (i, j, k)
+                })
+            })
+            .collect();
+        Self {
+            generators,
+            vertices,
+            edges,
+            _op: PhantomData,
+        }
+    }
+
+    pub fn generators(&self) -> &[G] {
+        &self.generators
+    }
+
+    /// The elements of the generated group, starting with the identity
+    pub fn vertices(&self) -> &[G] {
+        &self.vertices
+    }
+
+    /// The edges as `(from, to, generator)` triples of indices into [`vertices`](Self::vertices)
+    /// and [`generators`](Self::generators)
+    pub fn edges(&self) -> &[(usize, usize, usize)] {
+        &self.edges
+    }
+
+    /// Renders the graph as a Graphviz DOT document, using `label` to name the vertices.
+    ///
+    /// Each generator gets its own edge colour, and every edge is labelled with its generator.
+    pub fn to_dot(&self, label: impl Fn(&G) -> String) -> String {
+        let escape = |s: String| s.replace('\\', "\\\\").replace('"', "\\\"");
+        let mut out = String::from("digraph cayley {\n");
+        for (i, x) in self.vertices.iter().enumerate() {
+            out += &format!("    {i} [label=\"{}\"];\n", escape(label(x)));
+        }
+        for &(i, j, k) in &self.edges {
+            out += &format!(
+                "    {i} -> {j} [color=\"{}\", label=\"{}\"];\n",
+                COLORS[k % COLORS.len()],
+                escape(label(&self.generators[k]))
+            );
+        }
+        out += "}\n";
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::{Addition, BinOp},
+        primitives::CyclicNumber,
+    };
+
+    use super::*;
+
+    #[test]
+    fn cyclic_group() {
+        let graph: CayleyGraph<CyclicNumber<3>, Addition> = CayleyGraph::new([1.into()]);
+        assert_eq!(graph.edges(), &[(0, 1, 0), (1, 2, 0), (2, 0, 0)]);
+        assert_eq!(
+            graph.to_dot(|x| format!("{x:?}")),
+            "digraph cayley {\n    \
+                 0 [label=\"CyclicNumber(0)\"];\n    \
+                 1 [label=\"CyclicNumber(1)\"];\n    \
+                 2 [label=\"CyclicNumber(2)\"];\n    \
+                 0 -> 1 [color=\"red\", label=\"CyclicNumber(1)\"];\n    \
+                 1 -> 2 [color=\"red\", label=\"CyclicNumber(1)\"];\n    \
+                 2 -> 0 [color=\"red\", label=\"CyclicNumber(1)\"];\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn edges_follow_generators() {
+        let graph: CayleyGraph<CyclicNumber<6>, Addition> = CayleyGraph::new([2.into(), 3.into()]);
+        assert_eq!(graph.vertices().len(), 6);
+        for &(i, j, k) in graph.edges() {
+            let v = graph.vertices();
+            assert_eq!(BinOp::<Addition>::op(&v[i], &graph.generators()[k]), v[j]);
+        }
+    }
+}
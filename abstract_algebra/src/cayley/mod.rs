@@ -0,0 +1,6 @@
+//! Cayley tables and Cayley graphs of finite groups
+mod graph;
+mod table;
+
+pub use graph::*;
+pub use table::*;
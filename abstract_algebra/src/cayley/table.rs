@@ -0,0 +1,246 @@
+use std::{fmt, marker::PhantomData};
+
+use crate::{
+    ops::{BinOp, Multiplication, OperationKind},
+    structures::FiniteGroup,
+};
+
+/// The formats a [`CayleyTable`] can be rendered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    /// Aligned plain text columns
+    Text,
+    Markdown,
+    Csv,
+    /// A LaTeX `tabular` environment with every label typeset in math mode
+    Latex,
+}
+
+/// The operation table of a finite set of elements closed under a binary operation.
+///
+/// Row `i` and column `j` hold the product `elements[i] * elements[j]`.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::ops::Addition;
+/// use abstract_algebra::primitives::CyclicNumber;
+/// use abstract_algebra::cayley::{CayleyTable, TableFormat};
+///
+/// let table = CayleyTable::<CyclicNumber<3>, Addition>::of_group();
+/// let labels = ["0", "1", "2"];
+/// let label = |x: &CyclicNumber<3>| {
+///     labels[table.elements().iter().position(|y| y == x).unwrap()].to_string()
+/// };
+/// assert_eq!(
+///     table.render(TableFormat::Markdown, label),
+///     "| + | 0 | 1 | 2 |\n\
+///      |---|---|---|---|\n\
+///      | **0** | 0 | 1 | 2 |\n\
+///      | **1** | 1 | 2 | 0 |\n\
+///      | **2** | 2 | 0 | 1 |\n"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct CayleyTable<T: BinOp<Op>, Op: OperationKind = Multiplication> {
+    elements: Vec<T>,
+    products: Vec<Vec<usize>>,
+    _op: PhantomData<Op>,
+}
+
+impl<T: BinOp<Op>, Op: OperationKind> CayleyTable<T, Op> {
+    /// The table of the given elements in the given order, or `None` if some product is not
+    /// among them
+    pub fn new(elements: impl IntoIterator<Item = T>) -> Option<Self> {
+        let elements: Vec<T> = elements.into_iter().collect();
+        let products = elements
+            .iter()
+            .map(|x| {
+                elements
+                    .iter()
+                    .map(|y| {
+                        let z = x.op(y);
+                        elements.iter().position(|e| *e == z)
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self {
+            elements,
+            products,
+            _op: PhantomData,
+        })
+    }
+
+    /// The table of all elements of a finite group, listed in the order of
+    /// [`FiniteGroup::elements`]
+    pub fn of_group() -> Self
+    where
+        T: FiniteGroup<Op>,
+    {
+        Self::new(T::elements()).expect("groups are closed under their operation")
+    }
+
+    /// The elements labelling the rows and columns
+    pub fn elements(&self) -> &[T] {
+        &self.elements
+    }
+
+    /// The product of the `i`-th and the `j`-th element
+    pub fn product(&self, i: usize, j: usize) -> &T {
+        &self.elements[self.products[i][j]]
+    }
+
+    /// The index of the product of the `i`-th and the `j`-th element
+    pub fn product_index(&self, i: usize, j: usize) -> usize {
+        self.products[i][j]
+    }
+
+    /// Renders the table, using `label` to name the elements
+    pub fn render(&self, format: TableFormat, label: impl Fn(&T) -> String) -> String {
+        let labels: Vec<String> = self.elements.iter().map(label).collect();
+        let rows: Vec<(&str, Vec<&str>)> = self
+            .products
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let cells = row.iter().map(|&k| labels[k].as_str()).collect();
+                (labels[i].as_str(), cells)
+            })
+            .collect();
+        let header: Vec<&str> = labels.iter().map(String::as_str).collect();
+
+        let mut out = String::new();
+        match format {
+            TableFormat::Text => {
+                let width = labels
+                    .iter()
+                    .map(|l| l.chars().count())
+                    .chain([Op::SYMBOL.chars().count()])
+                    .max()
+                    .unwrap_or(0);
+                let line = |first: &str, cells: &[&str]| {
+                    let cells: Vec<String> = cells.iter().map(|c| format!("{c:width$}")).collect();
+                    format!("{first:width$} | {}", cells.join(" "))
+                        .trim_end()
+                        .to_string()
+                        + "\n"
+                };
+                out += &line(Op::SYMBOL, &header);
+                let cells_width = (width + 1) * header.len().saturating_sub(1) + width;
+                out += &format!("{}-+-{}\n", "-".repeat(width), "-".repeat(cells_width));
+                for (first, cells) in rows {
+                    out += &line(first, &cells);
+                }
+            }
+            TableFormat::Markdown => {
+                let escape = |s: &str| s.replace('|', "\\|");
+                let line = |first: String, cells: &[&str]| {
+                    let cells: Vec<String> = cells.iter().map(|c| escape(c)).collect();
+                    format!("| {first} | {} |\n", cells.join(" | "))
+                };
+                out += &line(Op::SYMBOL.to_string(), &header);
+                out += &format!("|{}\n", "---|".repeat(header.len() + 1));
+                for (first, cells) in rows {
+                    out += &line(format!("**{}**", escape(first)), &cells);
+                }
+            }
+            TableFormat::Csv => {
+                let escape = |s: &str| {
+                    if s.contains([',', '"', '\n']) {
+                        format!("\"{}\"", s.replace('"', "\"\""))
+                    } else {
+                        s.to_string()
+                    }
+                };
+                let line = |first: &str, cells: &[&str]| {
+                    let fields: Vec<String> =
+                        [first].iter().chain(cells).map(|c| escape(c)).collect();
+                    fields.join(",") + "\n"
+                };
+                out += &line(Op::SYMBOL, &header);
+                for (first, cells) in rows {
+                    out += &line(first, &cells);
+                }
+            }
+            TableFormat::Latex => {
+                let line = |first: &str, cells: &[&str]| {
+                    let fields: Vec<String> = [first]
+                        .iter()
+                        .chain(cells)
+                        .map(|c| format!("${c}$"))
+                        .collect();
+                    format!("{} \\\\\n", fields.join(" & "))
+                };
+                let symbol = match Op::SYMBOL {
+                    "*" => "\\cdot",
+                    symbol => symbol,
+                };
+                out += &format!("\\begin{{tabular}}{{c|{}}}\n", "c".repeat(header.len()));
+                out += &line(symbol, &header);
+                out += "\\hline\n";
+                for (first, cells) in rows {
+                    out += &line(first, &cells);
+                }
+                out += "\\end{tabular}\n";
+            }
+        }
+        out
+    }
+}
+
+/// Renders the table as [plain text](TableFormat::Text), labelling the elements by their
+/// [`Debug`](fmt::Debug) representation
+impl<T: BinOp<Op>, Op: OperationKind> fmt::Display for CayleyTable<T, Op> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(TableFormat::Text, |x| format!("{x:?}")))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::Addition,
+        primitives::{CyclicNumber, Permutation},
+    };
+
+    use super::*;
+
+    fn label(x: &CyclicNumber<2>) -> String {
+        if *x == 0.into() { "e" } else { "a" }.to_string()
+    }
+
+    #[test]
+    fn formats() {
+        let table = CayleyTable::<CyclicNumber<2>, Addition>::of_group();
+        assert_eq!(
+            table.render(TableFormat::Text, label),
+            "+ | e a\n--+----\ne | e a\na | a e\n"
+        );
+        assert_eq!(
+            table.render(TableFormat::Csv, label),
+            "+,e,a\ne,e,a\na,a,e\n"
+        );
+        assert_eq!(
+            table.render(TableFormat::Latex, label),
+            "\\begin{tabular}{c|cc}\n$+$ & $e$ & $a$ \\\\\n\\hline\n$e$ & $e$ & $a$ \\\\\n$a$ & $a$ & $e$ \\\\\n\\end{tabular}\n"
+        );
+        assert_eq!(
+            table.render(TableFormat::Csv, |x| format!("{x:?}, {x:?}")),
+            "+,\"CyclicNumber(0), CyclicNumber(0)\",\"CyclicNumber(1), CyclicNumber(1)\"\n\
+             \"CyclicNumber(0), CyclicNumber(0)\",\"CyclicNumber(0), CyclicNumber(0)\",\"CyclicNumber(1), CyclicNumber(1)\"\n\
+             \"CyclicNumber(1), CyclicNumber(1)\",\"CyclicNumber(1), CyclicNumber(1)\",\"CyclicNumber(0), CyclicNumber(0)\"\n"
+        );
+    }
+
+    #[test]
+    fn table_entries() {
+        let table = CayleyTable::<Permutation<3>>::of_group();
+        for (i, x) in table.elements().iter().enumerate() {
+            for (j, y) in table.elements().iter().enumerate() {
+                assert_eq!(*table.product(i, j), x.op(y));
+            }
+        }
+        assert!(CayleyTable::<CyclicNumber<4>, Addition>::new([0.into(), 1.into()]).is_none());
+        assert!(CayleyTable::<CyclicNumber<4>, Addition>::new([0.into(), 2.into()]).is_some());
+    }
+}
@@ -36,11 +36,12 @@ pub(crate) mod private {
     pub trait Marker: Clone + Copy + Default + fmt::Debug + PartialEq + Eq {}
 }
 
+pub mod actions;
+pub mod cayley;
 pub mod ops;
 pub mod primitives;
 pub mod structures;
 mod utils;
-// mod actions;
 pub mod wrapper;
 
 pub use abstract_algebra_macros::*;
@@ -2,19 +2,26 @@ use std::fmt::Debug;
 
 use crate::private::{Marker, Seal};
 
-pub trait OperationKind: Seal + Marker {}
+pub trait OperationKind: Seal + Marker {
+    /// The symbol used when writing the operation down, e.g. in a Cayley table
+    const SYMBOL: &'static str;
+}
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct Multiplication;
 impl Seal for Multiplication {}
 impl Marker for Multiplication {}
-impl OperationKind for Multiplication {}
+impl OperationKind for Multiplication {
+    const SYMBOL: &'static str = "*";
+}
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct Addition;
 impl Seal for Addition {}
 impl Marker for Addition {}
-impl OperationKind for Addition {}
+impl OperationKind for Addition {
+    const SYMBOL: &'static str = "+";
+}
 
 pub trait BinOp<Op: OperationKind>: Sized + PartialEq + Eq + Clone + Debug {
     fn op(&self, rhs: &Self) -> Self;
@@ -57,3 +64,32 @@ where
         assert_eq!(a.op(b), b.op(a), "ab = ba");
     }
 }
+
+#[cfg(test)]
+pub fn test_finite_group<G, K>()
+where
+    G: crate::structures::FiniteGroup<K>,
+    K: OperationKind,
+{
+    let elems: Vec<G> = G::elements().collect();
+    test_enumeration::<G, K>(&elems);
+}
+
+/// Checks that `elems` lists every element of `G` exactly once, starting with the identity
+#[cfg(test)]
+pub fn test_enumeration<G, K>(elems: &[G])
+where
+    G: crate::structures::FiniteGroup<K>,
+    K: OperationKind,
+{
+    assert_eq!(elems.len(), G::order(), "|elements| = order");
+    assert_eq!(elems[0], G::id(), "the first element is the identity");
+    test_distinct(elems);
+}
+
+#[cfg(test)]
+pub fn test_distinct<T: PartialEq + Debug>(elems: &[T]) {
+    for (i, x) in elems.iter().enumerate() {
+        assert!(!elems[..i].contains(x), "{x:?} is listed twice");
+    }
+}
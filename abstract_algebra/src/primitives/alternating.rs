@@ -0,0 +1,158 @@
+use std::{error, fmt};
+
+use crate::{
+    actions::LeftAction,
+    ops::{Associativity, BinOp, Identity, Invertible, Multiplication},
+    primitives::{Lexicographic, OnPoints, Permutation},
+    structures::FiniteGroup,
+};
+
+/// An element of the alternating group `A_N`, i.e. an even [`Permutation`].
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::perm;
+/// use abstract_algebra::ops::{BinOp, Identity};
+/// use abstract_algebra::primitives::{Alternating, OddPermutationError};
+/// use abstract_algebra::structures::FiniteGroup;
+///
+/// let x = Alternating::try_from(perm![5; (1 2)(3 4)]).unwrap();
+/// let y = Alternating::try_from(perm![5; (1 2 3 4 5)]).unwrap();
+/// assert_eq!(x.op(&y).to_string(), "(2 4 5)");
+/// assert_eq!(Alternating::try_from(perm![5; (1 2)]), Err(OddPermutationError));
+/// assert_eq!(Alternating::<5>::order(), 60);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Alternating<const N: usize>(Permutation<N>);
+
+/// The error returned when converting an odd permutation into an [`Alternating`] element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OddPermutationError;
+
+impl fmt::Display for OddPermutationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the permutation is odd")
+    }
+}
+
+impl error::Error for OddPermutationError {}
+
+impl<const N: usize> TryFrom<Permutation<N>> for Alternating<N> {
+    type Error = OddPermutationError;
+
+    fn try_from(value: Permutation<N>) -> Result<Self, Self::Error> {
+        if value.is_even() {
+            Ok(Self(value))
+        } else {
+            Err(OddPermutationError)
+        }
+    }
+}
+
+impl<const N: usize> From<Alternating<N>> for Permutation<N> {
+    fn from(value: Alternating<N>) -> Self {
+        value.0
+    }
+}
+
+impl<const N: usize> Alternating<N> {
+    pub fn as_permutation(&self) -> &Permutation<N> {
+        &self.0
+    }
+}
+
+impl<const N: usize> Associativity<Multiplication> for Alternating<N> {}
+
+impl<const N: usize> BinOp<Multiplication> for Alternating<N> {
+    fn op(&self, rhs: &Self) -> Self {
+        Self(self.0.op(&rhs.0))
+    }
+}
+
+impl<const N: usize> Identity<Multiplication> for Alternating<N> {
+    fn id() -> Self {
+        Self(Permutation::id())
+    }
+}
+
+impl<const N: usize> Invertible<Multiplication> for Alternating<N> {
+    fn inv(&self) -> Self {
+        Self(self.0.inv())
+    }
+}
+
+/// Lists the even permutations in lexicographic order
+impl<const N: usize> FiniteGroup<Multiplication> for Alternating<N> {
+    fn elements() -> impl Iterator<Item = Self> {
+        Lexicographic::new().filter(Permutation::is_even).map(Self)
+    }
+    /// # Panics
+    /// if `N!` does not fit into a `usize`, in which case `N!/2` does not either
+    fn order() -> usize {
+        Permutation::<N>::order().div_ceil(2)
+    }
+    fn element_order(&self) -> usize {
+        self.0.element_order()
+    }
+}
+
+/// Writes the permutation in cycle notation
+impl<const N: usize> fmt::Display for Alternating<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<const N: usize> LeftAction<Alternating<N>, usize> for OnPoints {
+    fn act(&self, g: &Alternating<N>, x: &usize) -> usize {
+        g.0.image(*x)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::Addition,
+        perm,
+        primitives::{CyclicNumber, Sign},
+        structures::{Homomorphism, Subgroup},
+    };
+
+    use super::*;
+
+    #[test]
+    fn alternating_group() {
+        assert_eq!(
+            Alternating::<4>::elements().count(),
+            Alternating::<4>::order()
+        );
+        assert_eq!(Alternating::<1>::order(), 1);
+        assert_eq!(Alternating::<1>::elements().count(), 1);
+        assert_eq!(Alternating::<20>::order(), 1_216_451_004_088_320_000);
+        let kernel = Homomorphism::<Permutation<4>, CyclicNumber<2>, _, Addition>::kernel(&Sign);
+        assert!(Alternating::<4>::elements().all(|x| kernel.contains(x.as_permutation())));
+        assert_eq!(Alternating::<4>::class_equation(), vec![1, 3, 4, 4]);
+        assert_eq!(Alternating::<5>::class_equation(), vec![1, 12, 12, 15, 20]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn order_overflow() {
+        Alternating::<21>::order();
+    }
+
+    #[test]
+    fn no_subgroup_of_order_six() {
+        // A_4 is the smallest group showing that the converse of Lagrange's theorem fails
+        let elements: Vec<Alternating<4>> = Alternating::elements().collect();
+        for x in &elements {
+            for y in &elements {
+                assert_ne!(Subgroup::generated_by([*x, *y]).order(), 6);
+            }
+        }
+        // A_5 is simple, so every non-trivial element generates all of it as a normal subgroup
+        let x = Alternating::try_from(perm![5; (1 2 3)]).unwrap();
+        let class = x.conjugacy_class();
+        assert_eq!(Subgroup::generated_by(class).order(), 60);
+    }
+}
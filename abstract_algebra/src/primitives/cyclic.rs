@@ -2,6 +2,7 @@ use abstract_algebra_macros::Operations;
 
 use crate::{
     ops::{Addition, Associativity, BinOp, Commutativity, Identity, Invertible, Multiplication},
+    structures::FiniteGroup,
     utils::is_prime,
 };
 
@@ -65,6 +66,15 @@ impl<const N: usize> Invertible<Addition> for CyclicNumber<N> {
     }
 }
 
+impl<const N: usize> FiniteGroup<Addition> for CyclicNumber<N> {
+    fn elements() -> impl Iterator<Item = Self> {
+        (0..N).map(Self)
+    }
+    fn order() -> usize {
+        N
+    }
+}
+
 impl<const N: usize> From<usize> for CyclicNumber<N> {
     fn from(value: usize) -> Self {
         Self(value % N)
@@ -73,7 +83,10 @@ impl<const N: usize> From<usize> for CyclicNumber<N> {
 
 #[cfg(test)]
 mod test {
-    use crate::structures::Ring;
+    use crate::{
+        ops::test_finite_group,
+        structures::{Group, Monoid, Ring},
+    };
 
     use super::*;
 
@@ -90,4 +103,20 @@ mod test {
         assert_eq!(two.add(&four), two);
         assert_eq!(three.add(&two), one);
     }
+
+    #[test]
+    fn pow() {
+        let three: CyclicNumber<7> = 3.into();
+        assert_eq!(
+            Group::<Addition>::powi(&three, 1_000_000_000_000_000_000),
+            3.into()
+        );
+        assert_eq!(Group::<Addition>::powi(&three, -2), 1.into());
+        assert_eq!(Monoid::<Multiplication>::pow(&three, 6), 1.into());
+    }
+
+    #[test]
+    fn finite_group() {
+        test_finite_group::<CyclicNumber<6>, Addition>();
+    }
 }
@@ -0,0 +1,230 @@
+use std::fmt;
+
+use crate::{
+    ops::{Associativity, BinOp, Identity, Invertible, Multiplication},
+    primitives::Permutation,
+    structures::FiniteGroup,
+};
+
+/// An element `a^k x^j` of the dicyclic group `Dic_N` of order `4N`, which is presented as
+/// `<a, x | a^{2N} = 1, x^2 = a^N, x a x^-1 = a^-1>`.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::ops::{BinOp, Identity, Invertible};
+/// use abstract_algebra::primitives::DicyclicElement;
+///
+/// let a = DicyclicElement::<3>::a();
+/// let x = DicyclicElement::<3>::x();
+/// assert_eq!(x.op(&x), a.op(&a).op(&a));
+/// assert_eq!(x.op(&a).op(&x.inv()), a.inv());
+/// assert_eq!(a.op(&x).to_string(), "a x");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DicyclicElement<const N: usize> {
+    power: usize,
+    x: bool,
+}
+
+/// An element of the quaternion group `Q_8 = Dic_2`, where `i = a`, `j = x` and `k = a x`
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::ops::{BinOp, Identity};
+/// use abstract_algebra::primitives::Quaternion;
+///
+/// let (i, j, k) = (Quaternion::i(), Quaternion::j(), Quaternion::k());
+/// assert_eq!(i.op(&j), k);
+/// assert_eq!(j.op(&i).to_string(), "-k");
+/// assert_eq!(i.op(&j).op(&k), Quaternion::minus_one());
+/// ```
+pub type Quaternion = DicyclicElement<2>;
+
+impl<const N: usize> DicyclicElement<N> {
+    /// The element `a^power x` if `x` is set and `a^power` otherwise
+    pub fn new(power: usize, x: bool) -> Self {
+        Self {
+            power: power % (2 * N),
+            x,
+        }
+    }
+
+    /// The generator `a` of order `2N`
+    pub fn a() -> Self {
+        Self::new(1, false)
+    }
+
+    /// The generator `x` of order `4`
+    pub fn x() -> Self {
+        Self::new(0, true)
+    }
+
+    /// The exponent `k` in `0..2N` of `a^k x^j`
+    pub fn power(&self) -> usize {
+        self.power
+    }
+
+    /// Whether the element is of the form `a^k x`
+    pub fn has_x(&self) -> bool {
+        self.x
+    }
+
+    /// The image under the left regular representation, i.e. the permutation of the elements
+    /// numbered as in [`FiniteGroup::elements`] induced by multiplication from the left
+    pub fn to_permutation<const M: usize>(&self) -> Permutation<M> {
+        const { assert!(M == 4 * N, "M has to be 4 * N") }
+        Permutation::left_regular(self)
+    }
+}
+
+impl Quaternion {
+    pub fn minus_one() -> Self {
+        Self::new(2, false)
+    }
+
+    pub fn i() -> Self {
+        Self::new(1, false)
+    }
+
+    pub fn j() -> Self {
+        Self::new(0, true)
+    }
+
+    pub fn k() -> Self {
+        Self::new(1, true)
+    }
+}
+
+impl<const N: usize> Associativity<Multiplication> for DicyclicElement<N> {}
+
+impl<const N: usize> BinOp<Multiplication> for DicyclicElement<N> {
+    fn op(&self, rhs: &Self) -> Self {
+        // x a^l = a^-l x and x^2 = a^N
+        let power = if self.x {
+            self.power + 2 * N - rhs.power
+        } else {
+            self.power + rhs.power
+        };
+        let square = if self.x && rhs.x { N } else { 0 };
+        Self::new(power + square, self.x ^ rhs.x)
+    }
+}
+
+impl<const N: usize> Identity<Multiplication> for DicyclicElement<N> {
+    fn id() -> Self {
+        Self::new(0, false)
+    }
+}
+
+impl<const N: usize> Invertible<Multiplication> for DicyclicElement<N> {
+    fn inv(&self) -> Self {
+        if self.x {
+            // (a^k x)^2 = a^N, which is central and of order 2
+            Self::new(self.power + N, true)
+        } else {
+            Self::new(2 * N - self.power, false)
+        }
+    }
+}
+
+/// Lists the elements `a^k` first, followed by the elements `a^k x`
+impl<const N: usize> FiniteGroup<Multiplication> for DicyclicElement<N> {
+    fn elements() -> impl Iterator<Item = Self> {
+        (0..4 * N).map(|i| Self::new(i % (2 * N), i >= 2 * N))
+    }
+    fn order() -> usize {
+        4 * N
+    }
+}
+
+/// Writes the element as `a^k`, `a^k x` or, for the quaternion group, as one of `±1`, `±i`,
+/// `±j` and `±k`
+impl<const N: usize> fmt::Display for DicyclicElement<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if N == 2 {
+            let sign = if self.power >= 2 { "-" } else { "" };
+            let unit = match (self.power % 2, self.x) {
+                (0, false) => "1",
+                (1, false) => "i",
+                (0, true) => "j",
+                _ => "k",
+            };
+            return write!(f, "{sign}{unit}");
+        }
+        match (self.power, self.x) {
+            (0, false) => f.write_str("1"),
+            (0, true) => f.write_str("x"),
+            (1, x) => f.write_str(if x { "a x" } else { "a" }),
+            (k, x) => write!(f, "a^{k}{}", if x { " x" } else { "" }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::{Addition, test_accociativity},
+        primitives::{CyclicNumber, DihedralElement},
+        structures::{Group, Monoid, Subgroup, is_isomorphic},
+    };
+
+    use super::*;
+
+    #[test]
+    fn quaternions() {
+        let units = [Quaternion::i(), Quaternion::j(), Quaternion::k()];
+        for u in &units {
+            assert_eq!(u.op(u), Quaternion::minus_one());
+            assert_eq!(u.element_order(), 4);
+        }
+        let elements: Vec<Quaternion> = Quaternion::elements().collect();
+        let names: Vec<String> = elements.iter().map(|q| q.to_string()).collect();
+        assert_eq!(names, ["1", "i", "-1", "-i", "j", "k", "-j", "-k"]);
+        test_accociativity(&elements);
+        assert_eq!(Quaternion::class_equation(), vec![1, 1, 2, 2, 2]);
+        // Every subgroup of Q_8 is normal although Q_8 is not abelian
+        for q in &elements {
+            assert!(Subgroup::generated_by([*q]).is_normal());
+        }
+        assert!(!is_isomorphic::<Quaternion, DihedralElement<4>, _, _>());
+    }
+
+    #[test]
+    fn dicyclic() {
+        type Dic3 = DicyclicElement<3>;
+        let elements: Vec<Dic3> = Dic3::elements().collect();
+        assert_eq!(elements.len(), 12);
+        test_accociativity(&elements);
+        for x in &elements {
+            assert_eq!(x.op(&x.inv()), Dic3::id());
+        }
+        assert_eq!(Subgroup::<Dic3>::center().order(), 2);
+        assert_eq!(Dic3::a().op(&Dic3::x()).to_string(), "a x");
+        assert_eq!(Dic3::a().pow(4).to_string(), "a^4");
+        assert_eq!(Dic3::x().powi(-1).to_string(), "a^3 x");
+        assert!(is_isomorphic::<
+            DicyclicElement<1>,
+            CyclicNumber<4>,
+            Multiplication,
+            Addition,
+        >());
+    }
+
+    #[test]
+    fn regular_representation() {
+        let elements: Vec<Quaternion> = Quaternion::elements().collect();
+        for x in &elements {
+            for y in &elements {
+                assert_eq!(
+                    x.op(y).to_permutation::<8>(),
+                    x.to_permutation::<8>().op(&y.to_permutation())
+                );
+            }
+            // Only the identity fixes a point
+            assert_eq!(
+                x.to_permutation::<8>().fixed_points().is_empty(),
+                *x != Quaternion::id()
+            );
+        }
+    }
+}
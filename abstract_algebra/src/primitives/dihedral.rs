@@ -1,5 +1,29 @@
-use crate::ops::{Associativity, BinOp, Identity, Invertible, Multiplication};
+use std::fmt;
 
+use crate::{
+    actions::LeftAction,
+    ops::{Addition, Associativity, BinOp, Identity, Invertible, Multiplication},
+    primitives::{CyclicNumber, Permutation},
+    structures::{FiniteGroup, Homomorphism},
+};
+
+/// An element of the dihedral group `D_N` of order `2N`, the symmetries of the regular `N`-gon.
+///
+/// `DihedralElement::new(k, flipped)` is the rotation `r^k` if `flipped` is false and the
+/// reflection `s r^k = r^-k s` otherwise, where `r` is the rotation by one vertex and `s` the
+/// reflection fixing vertex `1`.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::ops::{BinOp, Invertible};
+/// use abstract_algebra::primitives::DihedralElement;
+///
+/// let (r, s) = (DihedralElement::<6>::r(), DihedralElement::<6>::s());
+/// assert_eq!(s.op(&r).op(&s), r.inv());
+/// assert_eq!(s.op(&r).op(&r).to_string(), "s r^2");
+/// assert_eq!(r.op(&s).to_string(), "s r^5");
+/// assert_eq!(r.op(&s).to_permutation().to_string(), "(1 2)(3 6)(4 5)");
+/// ```
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct DihedralElement<const N: usize> {
     flipped: bool,
@@ -8,9 +32,47 @@ pub struct DihedralElement<const N: usize> {
 
 impl<const N: usize> DihedralElement<N> {
     pub fn new(rotation: usize, flipped: bool) -> Self {
-        Self { flipped, rotation }
+        Self {
+            flipped,
+            rotation: rotation % N,
+        }
+    }
+
+    /// The rotation `r` by one vertex, of order `N`
+    pub fn r() -> Self {
+        Self::new(1, false)
+    }
+
+    /// The reflection `s` fixing vertex `1`, of order `2`
+    pub fn s() -> Self {
+        Self::new(0, true)
+    }
+
+    /// The exponent `k` in `0..N` such that the element is `r^k` or `s r^k`
+    ///
+    /// # Example
+    /// ```rust
+    /// use abstract_algebra::primitives::DihedralElement;
+    ///
+    /// let x = DihedralElement::<5>::new(7, true);
+    /// assert_eq!(x.rotation(), 2);
+    /// assert_eq!(x.to_string(), "s r^2");
+    /// ```
+    pub fn rotation(&self) -> usize {
+        self.rotation
+    }
+
+    /// Whether the element is a reflection
+    pub fn is_flipped(&self) -> bool {
+        self.flipped
+    }
+
+    /// The permutation of the vertices under [`OnVertices`]
+    pub fn to_permutation(&self) -> Permutation<N> {
+        Permutation::new_unchecked(core::array::from_fn(|i| OnVertices.act(self, &(i + 1))))
     }
 }
+
 impl<const N: usize> Associativity<Multiplication> for DihedralElement<N> {}
 
 impl<const N: usize> BinOp<Multiplication> for DihedralElement<N> {
@@ -54,8 +116,77 @@ impl<const N: usize> Invertible<Multiplication> for DihedralElement<N> {
     }
 }
 
+impl<const N: usize> FiniteGroup<Multiplication> for DihedralElement<N> {
+    fn elements() -> impl Iterator<Item = Self> {
+        (0..2 * N).map(|i| Self {
+            flipped: i >= N,
+            rotation: i % N,
+        })
+    }
+    fn order() -> usize {
+        2 * N
+    }
+}
+
+/// Writes rotations as `r^k` and reflections as `s r^k`, where `k` is the
+/// [`rotation`](DihedralElement::rotation)
+impl<const N: usize> fmt::Display for DihedralElement<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = if self.flipped { "s " } else { "" };
+        match self.rotation {
+            0 if self.flipped => f.write_str("s"),
+            0 => f.write_str("1"),
+            1 => write!(f, "{prefix}r"),
+            k => write!(f, "{prefix}r^{k}"),
+        }
+    }
+}
+
+/// The homomorphism `D_N -> Z/2Z` mapping rotations to `0` and reflections to `1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Orientation;
+
+impl<const N: usize> Homomorphism<DihedralElement<N>, CyclicNumber<2>, Multiplication, Addition>
+    for Orientation
+{
+    fn apply(&self, x: &DihedralElement<N>) -> CyclicNumber<2> {
+        usize::from(x.flipped).into()
+    }
+}
+
+/// The action of `D_N` on the vertices `1..=N` of the regular `N`-gon, labelled
+/// counterclockwise, where the rotation `(1, false)` maps `v` to `v + 1` and the reflection
+/// `(0, true)` maps `v` to `2 - v` (mod `N`), and on colourings `[T; N]` of these vertices
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OnVertices;
+
+impl<const N: usize> LeftAction<DihedralElement<N>, usize> for OnVertices {
+    fn act(&self, g: &DihedralElement<N>, v: &usize) -> usize {
+        let rotated = (v - 1 + g.rotation) % N;
+        if g.flipped {
+            (N - rotated) % N + 1
+        } else {
+            rotated + 1
+        }
+    }
+}
+
+/// The action of `D_N` on colourings of the vertices, where the entry at position `v - 1` is the
+/// colour of vertex `v`, moving the colour of vertex `v` to `g·v`
+impl<T: Clone, const N: usize> LeftAction<DihedralElement<N>, [T; N]> for OnVertices {
+    fn act(&self, g: &DihedralElement<N>, x: &[T; N]) -> [T; N] {
+        let g = g.inv();
+        core::array::from_fn(|i| x[self.act(&g, &(i + 1)) - 1].clone())
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use crate::{
+        ops::{test_accociativity, test_distinct, test_finite_group},
+        structures::{Group, Monoid},
+    };
+
     use super::*;
     #[test]
     fn dihedral() {
@@ -66,4 +197,102 @@ mod test {
         assert_eq!(tau.op(&tau), id);
         assert_eq!(tau.op(&sigma).op(&tau), sigma.inv());
     }
+
+    #[test]
+    fn element_order() {
+        let tau = DihedralElement::<6>::new(0, true);
+        let sigma = DihedralElement::<6>::new(2, false);
+        assert_eq!(sigma.element_order(), 3);
+        assert_eq!(tau.element_order(), 2);
+        assert_eq!(tau.op(&sigma).element_order(), 2);
+        assert_eq!(sigma.commutator(&tau), sigma.pow(2));
+    }
+
+    #[test]
+    fn orientation() {
+        let sigma = DihedralElement::<6>::new(1, false);
+        let tau = DihedralElement::<6>::new(0, true);
+        assert!(
+            Homomorphism::<_, CyclicNumber<2>, _, Addition>::is_homomorphism_on(
+                &Orientation,
+                &[sigma, tau]
+            )
+        );
+        let kernel = Homomorphism::<_, CyclicNumber<2>, _, Addition>::kernel(&Orientation);
+        assert_eq!(kernel.generators(), &[sigma]);
+        assert_eq!(kernel.order(), 6);
+    }
+
+    #[test]
+    fn finite_group() {
+        test_finite_group::<DihedralElement<4>, _>();
+    }
+
+    #[test]
+    fn on_vertices() {
+        let sigma = DihedralElement::<5>::new(1, false);
+        let tau = DihedralElement::<5>::new(0, true);
+        assert!(OnVertices.is_action_on(&[sigma, tau], &[1, 2, 3, 4, 5]));
+        assert_eq!(OnVertices.act(&sigma, &5), 1);
+        assert_eq!(OnVertices.act(&tau, &2), 5);
+        // Only the identity fixes two adjacent vertices
+        let fixing: Vec<_> = DihedralElement::<5>::elements()
+            .filter(|g| OnVertices.act(g, &1) == 1 && OnVertices.act(g, &2) == 2)
+            .collect();
+        assert_eq!(fixing, [DihedralElement::id()]);
+    }
+
+    fn assert_matches_permutations<const N: usize>() {
+        let elements: Vec<DihedralElement<N>> = DihedralElement::elements().collect();
+        let perms: Vec<Permutation<N>> = elements.iter().map(|x| x.to_permutation()).collect();
+        for (i, x) in elements.iter().enumerate() {
+            for (j, y) in elements.iter().enumerate() {
+                assert_eq!(x.op(y).to_permutation(), perms[i].op(&perms[j]));
+            }
+            assert_eq!(x.inv().to_permutation(), perms[i].inv());
+            for v in 1..=N {
+                assert_eq!(perms[i].image(v), OnVertices.act(x, &v));
+            }
+        }
+        if N > 2 {
+            test_distinct(&perms);
+        }
+        test_accociativity(&elements);
+    }
+
+    #[test]
+    fn permutation_model() {
+        assert_matches_permutations::<1>();
+        assert_matches_permutations::<2>();
+        assert_matches_permutations::<3>();
+        assert_matches_permutations::<4>();
+        assert_matches_permutations::<5>();
+        assert_matches_permutations::<6>();
+        assert_matches_permutations::<7>();
+        assert_matches_permutations::<8>();
+        let (r, s) = (DihedralElement::<4>::r(), DihedralElement::<4>::s());
+        assert_eq!(r.to_permutation().to_string(), "(1 2 3 4)");
+        assert_eq!(s.to_permutation().to_string(), "(2 4)");
+        // The composite applies the right factor first
+        assert_eq!(r.op(&s).to_permutation().to_string(), "(1 2)(3 4)");
+        assert_eq!(s.op(&r).to_permutation().to_string(), "(1 4)(2 3)");
+    }
+
+    #[test]
+    fn accessors_and_display() {
+        let names: Vec<String> = DihedralElement::<4>::elements()
+            .map(|x| x.to_string())
+            .collect();
+        assert_eq!(
+            names,
+            ["1", "r", "r^2", "r^3", "s", "s r", "s r^2", "s r^3"]
+        );
+        let (r, s) = (DihedralElement::<5>::r(), DihedralElement::<5>::s());
+        let x = r.pow(3).op(&s);
+        assert!(x.is_flipped());
+        assert_eq!(x.rotation(), 2);
+        assert_eq!(x, s.op(&r.pow(2)));
+        assert_eq!(x.to_string(), "s r^2");
+        assert_eq!(DihedralElement::<5>::new(7, false), r.pow(2));
+    }
 }
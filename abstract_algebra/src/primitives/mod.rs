@@ -1,10 +1,15 @@
+mod alternating;
 mod cyclic;
+mod dicyclic;
 mod dihedral;
 mod numbers;
 mod perm;
+mod product;
 mod word;
 
+pub use alternating::*;
 pub use cyclic::*;
+pub use dicyclic::*;
 pub use dihedral::*;
 pub use numbers::*;
 pub use perm::*;
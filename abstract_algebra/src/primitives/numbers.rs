@@ -1,6 +1,7 @@
 use crate::{
     ops::{Addition, Associativity, BinOp, Commutativity, Identity, Invertible, Multiplication},
-    structures::{FractionField, Integrality},
+    primitives::CyclicNumber,
+    structures::{FractionField, Homomorphism, Integrality},
     wrapper::Wrapper,
 };
 
@@ -76,14 +77,25 @@ impl Identity<Multiplication> for isize {
 
 impl Integrality for isize {}
 
+/// The reduction homomorphism `Z -> Z/NZ`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reduction;
+
+impl<const N: usize> Homomorphism<Integer, CyclicNumber<N>, Addition, Addition> for Reduction {
+    fn apply(&self, x: &Integer) -> CyclicNumber<N> {
+        x.rem_euclid(N as isize).unsigned_abs().into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
         ops::{Addition, Multiplication},
-        structures::{Monoid, Ring},
+        primitives::CyclicNumber,
+        structures::{Homomorphism, Monoid, Ring},
     };
 
-    use super::{Integer, Natural};
+    use super::{Integer, Natural, Reduction};
 
     #[test]
     fn monoid() {
@@ -99,6 +111,21 @@ mod test {
         assert_eq!(one * one, one);
         assert_eq!(one + one, two);
         assert_eq!(two * two, two + two);
+        assert_eq!(Monoid::<Multiplication>::pow(&two, 10), Natural::new(1024));
+        assert_eq!(Monoid::<Addition>::pow(&two, 10), Natural::new(20));
+    }
+
+    #[test]
+    fn reduction() {
+        let phi = |x: &Integer| -> CyclicNumber<4> { Reduction.apply(x) };
+        assert_eq!(phi(&Integer::new(-1)), 3.into());
+        assert_eq!(phi(&Integer::new(9)), 1.into());
+        for a in -10..10 {
+            for b in -10..10 {
+                let (a, b) = (Integer::new(a), Integer::new(b));
+                assert_eq!(phi(&(a + b)), phi(&a).add(&phi(&b)));
+            }
+        }
     }
 
     #[test]
@@ -0,0 +1,141 @@
+use crate::{primitives::perm::Permutation, utils::lcm};
+
+// The helpers below work on the images `images[i - 1]` of the points `i` in `1..=images.len()`,
+// which is shared by `Permutation` and `DynPermutation`
+
+/// All cycles including the fixed points, each starting with its smallest point and ordered by
+/// their smallest points
+fn all_cycles(images: &[usize]) -> Vec<Vec<usize>> {
+    let mut seen = vec![false; images.len()];
+    let mut cycles = Vec::new();
+    for start in 1..=images.len() {
+        let mut cycle = Vec::new();
+        let mut i = start;
+        while !seen[i - 1] {
+            seen[i - 1] = true;
+            cycle.push(i);
+            i = images[i - 1];
+        }
+        if !cycle.is_empty() {
+            cycles.push(cycle);
+        }
+    }
+    cycles
+}
+
+pub(super) fn cycles(images: &[usize]) -> Vec<Vec<usize>> {
+    all_cycles(images)
+        .into_iter()
+        .filter(|cycle| cycle.len() > 1)
+        .collect()
+}
+
+/// A permutation is even if and only if the number of points minus the number of cycles is even
+pub(super) fn is_even(images: &[usize]) -> bool {
+    (images.len() - all_cycles(images).len()).is_multiple_of(2)
+}
+
+pub(super) fn sign(images: &[usize]) -> isize {
+    if is_even(images) { 1 } else { -1 }
+}
+
+pub(super) fn element_order(images: &[usize]) -> usize {
+    all_cycles(images).iter().map(Vec::len).fold(1, lcm)
+}
+
+pub(super) fn support(images: &[usize]) -> Vec<usize> {
+    (1..=images.len()).filter(|&i| images[i - 1] != i).collect()
+}
+
+impl<const N: usize> Permutation<N> {
+    /// The decomposition into disjoint cycles of length at least 2, each starting with its
+    /// smallest point and ordered by their smallest points
+    ///
+    /// # Example
+    /// ```rust
+    /// use abstract_algebra::perm;
+    /// use abstract_algebra::ops::Identity;
+    ///
+    /// let x = perm![6; (5 4)(6 3 1)];
+    /// assert_eq!(x.cycles(), [vec![1, 6, 3], vec![4, 5]]);
+    /// assert_eq!(x.cycle_type(), [3, 2, 1]);
+    /// assert_eq!(x.support(), [1, 3, 4, 5, 6]);
+    /// assert_eq!(x.sign(), -1);
+    /// ```
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        cycles(&self.0)
+    }
+
+    /// The lengths of the cycles, including fixed points as cycles of length 1, in decreasing
+    /// order, i.e. a partition of `N`
+    pub fn cycle_type(&self) -> Vec<usize> {
+        let mut lengths: Vec<usize> = all_cycles(&self.0).iter().map(Vec::len).collect();
+        lengths.sort_by(|a, b| b.cmp(a));
+        lengths
+    }
+
+    /// Whether the permutation is a product of an even number of transpositions
+    pub fn is_even(&self) -> bool {
+        is_even(&self.0)
+    }
+
+    /// `1` for even and `-1` for odd permutations
+    pub fn sign(&self) -> isize {
+        sign(&self.0)
+    }
+
+    /// The points moved by the permutation in increasing order
+    pub fn support(&self) -> Vec<usize> {
+        support(&self.0)
+    }
+
+    /// The points fixed by the permutation in increasing order
+    pub fn fixed_points(&self) -> Vec<usize> {
+        (1..=N).filter(|&i| self.image(i) == i).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::{BinOp, Identity},
+        perm,
+        structures::{FiniteGroup, Group},
+    };
+
+    use super::*;
+
+    #[test]
+    fn cycles_rebuild_the_permutation() {
+        for x in Permutation::<5>::elements() {
+            let rebuilt = x.cycles().iter().fold(Permutation::id(), |y, cycle| {
+                let mut images: [usize; 5] = core::array::from_fn(|i| i + 1);
+                for (i, &point) in cycle.iter().enumerate() {
+                    images[point - 1] = cycle[(i + 1) % cycle.len()];
+                }
+                y.op(&Permutation::new_unchecked(images))
+            });
+            assert_eq!(rebuilt, x);
+            assert_eq!(x.cycle_type().iter().sum::<usize>(), 5);
+            assert_eq!(x.support().len() + x.fixed_points().len(), 5);
+        }
+        assert!(Permutation::<4>::id().cycles().is_empty());
+        assert_eq!(Permutation::<4>::id().cycle_type(), [1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn parity_and_order() {
+        for x in Permutation::<4>::elements() {
+            for y in Permutation::<4>::elements() {
+                assert_eq!(x.op(&y).sign(), x.sign() * y.sign());
+            }
+            assert_eq!(x.element_order(), x.order_bounded(24).unwrap());
+        }
+        assert!(perm![5; (1 2 3)].is_even());
+        assert!(!perm![5; (1 2 3 4)].is_even());
+        assert_eq!(perm![9; (1 2)(3 4 5)(6 7 8 9)].element_order(), 12);
+        // The order of S_24 does not even fit into a usize
+        let x = perm![24; (1 2 3 4 5 6 7 8 9 10 11 12 13)(14 15 16 17 18 19 20 21 22 23 24)];
+        assert_eq!(x.element_order(), 143);
+    }
+}
@@ -0,0 +1,301 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    ops::{Associativity, BinOp, Identity, Invertible, Multiplication},
+    primitives::{
+        ParsePermutationError, Permutation, PermutationError,
+        perm::{
+            cycles,
+            parse::{parse_cycles, write_cycles},
+            validate,
+        },
+    },
+};
+
+/// A permutation of the positive integers moving only finitely many points, whose degree is only
+/// known at runtime.
+///
+/// Points beyond the stored images are fixed, so permutations of different degrees can be
+/// composed and compared, which embeds every `S_N` into this group. The images are stored
+/// without trailing fixed points, such that the [`degree`](Self::degree) is the largest moved
+/// point. As the images are stored densely, parsing and validation reject points beyond
+/// [`MAX_DEGREE`](Self::MAX_DEGREE).
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::perm;
+/// use abstract_algebra::ops::{BinOp, Identity};
+/// use abstract_algebra::primitives::{DynPermutation, Permutation};
+///
+/// let x: DynPermutation = "(1 2 3)".parse().unwrap();
+/// let y = DynPermutation::from(perm![5; (4 5)]);
+/// let z = x.op(&y);
+/// assert_eq!(z.to_string(), "(1 2 3)(4 5)");
+/// assert_eq!(z.degree(), 5);
+/// assert_eq!(Permutation::<5>::try_from(z), Ok(perm![(1 2 3)(4 5)]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DynPermutation(Vec<usize>);
+
+impl TryFrom<Vec<usize>> for DynPermutation {
+    type Error = PermutationError;
+
+    /// Validates that `value` lists the images of `1..=value.len()` and that no point beyond
+    /// [`MAX_DEGREE`](DynPermutation::MAX_DEGREE) is moved
+    fn try_from(value: Vec<usize>) -> Result<Self, Self::Error> {
+        validate(&value)?;
+        let x = Self::new_unchecked(value);
+        if x.degree() > Self::MAX_DEGREE {
+            return Err(PermutationError::OutOfRange(x.degree()));
+        }
+        Ok(x)
+    }
+}
+
+impl<const N: usize> From<Permutation<N>> for DynPermutation {
+    fn from(value: Permutation<N>) -> Self {
+        Self::new_unchecked((1..=N).map(|i| value.image(i)).collect())
+    }
+}
+
+/// Restricts the permutation to `1..=N`, which fails if it moves a point beyond `N`
+impl<const N: usize> TryFrom<DynPermutation> for Permutation<N> {
+    type Error = PermutationError;
+
+    fn try_from(value: DynPermutation) -> Result<Self, Self::Error> {
+        if value.degree() > N {
+            return Err(PermutationError::DegreeTooLarge {
+                degree: value.degree(),
+                max: N,
+            });
+        }
+        Ok(Self::new_unchecked(core::array::from_fn(|i| {
+            value.image(i + 1)
+        })))
+    }
+}
+
+impl DynPermutation {
+    /// The largest point which may be moved, bounding the memory used for the images
+    pub const MAX_DEGREE: usize = 1 << 20;
+
+    /// Creates the permutation mapping `i` to `images[i - 1]` and fixing all points beyond
+    /// `images.len()`, without validating the images.
+    ///
+    /// The caller has to make sure that `images` is a permutation of `1..=images.len()`, as the
+    /// group operations may panic or give meaningless results otherwise.
+    pub fn new_unchecked(mut images: Vec<usize>) -> Self {
+        while images.last().is_some_and(|&image| image == images.len()) {
+            images.pop();
+        }
+        Self(images)
+    }
+
+    /// The largest moved point, or `0` for the identity
+    pub fn degree(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The image of the positive integer `point`
+    ///
+    /// # Panics
+    /// if `point` is `0`
+    pub fn image(&self, point: usize) -> usize {
+        assert!(point > 0, "points start at 1");
+        self.0.get(point - 1).copied().unwrap_or(point)
+    }
+
+    /// See [`Permutation::cycles`]
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        cycles::cycles(&self.0)
+    }
+
+    /// See [`Permutation::support`]
+    pub fn support(&self) -> Vec<usize> {
+        cycles::support(&self.0)
+    }
+
+    /// See [`Permutation::is_even`]
+    pub fn is_even(&self) -> bool {
+        cycles::is_even(&self.0)
+    }
+
+    /// See [`Permutation::sign`]
+    pub fn sign(&self) -> isize {
+        cycles::sign(&self.0)
+    }
+
+    /// The order of the permutation, computed from the cycle lengths as for [`Permutation`]
+    pub fn element_order(&self) -> usize {
+        cycles::element_order(&self.0)
+    }
+}
+
+impl Associativity<Multiplication> for DynPermutation {}
+
+impl BinOp<Multiplication> for DynPermutation {
+    fn op(&self, rhs: &Self) -> Self {
+        let degree = self.degree().max(rhs.degree());
+        Self::new_unchecked((1..=degree).map(|i| self.image(rhs.image(i))).collect())
+    }
+}
+
+impl Identity<Multiplication> for DynPermutation {
+    fn id() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl Invertible<Multiplication> for DynPermutation {
+    fn inv(&self) -> Self {
+        let mut inverse = vec![0; self.degree()];
+        for (i, &v) in self.0.iter().enumerate() {
+            inverse[v - 1] = i + 1
+        }
+        Self(inverse)
+    }
+}
+
+/// Writes the permutation in cycle notation like [`Permutation`]
+impl fmt::Display for DynPermutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_cycles(f, &self.cycles())
+    }
+}
+
+/// Parses a product of disjoint cycles like [`Permutation`], accepting the points in
+/// `1..=MAX_DEGREE`
+impl FromStr for DynPermutation {
+    type Err = ParsePermutationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cycles = parse_cycles(s, Self::MAX_DEGREE)?;
+        let degree = cycles.iter().flatten().copied().max().unwrap_or(0);
+        let mut images: Vec<usize> = (1..=degree).collect();
+        for cycle in cycles {
+            for (i, &point) in cycle.iter().enumerate() {
+                images[point - 1] = cycle[(i + 1) % cycle.len()];
+            }
+        }
+        Ok(Self::new_unchecked(images))
+    }
+}
+
+impl TryFrom<&str> for DynPermutation {
+    type Error = ParsePermutationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::test_accociativity,
+        perm,
+        structures::{FiniteGroup, Group, Subgroup},
+    };
+
+    use super::*;
+
+    #[test]
+    fn agrees_with_permutation() {
+        for x in Permutation::<4>::elements() {
+            let d = DynPermutation::from(x);
+            assert_eq!(d.to_string(), x.to_string());
+            assert_eq!(d.cycles(), x.cycles());
+            assert_eq!(d.sign(), x.sign());
+            assert_eq!(d.element_order(), x.element_order());
+            assert_eq!(d.inv(), DynPermutation::from(x.inv()));
+            for y in Permutation::<4>::elements() {
+                assert_eq!(d.op(&y.into()), DynPermutation::from(x.op(&y)));
+            }
+            assert_eq!(Permutation::try_from(d), Ok(x));
+        }
+    }
+
+    #[test]
+    fn mixing_degrees() {
+        // S_3 embedded into S_5 commutes with the transposition (4 5)
+        let t = DynPermutation::from(perm![5; (4 5)]);
+        for x in Permutation::<3>::elements() {
+            let x = DynPermutation::from(x);
+            assert_eq!(x.op(&t), t.op(&x));
+            assert!(x.degree() <= 3);
+        }
+        let x: DynPermutation = "(2 7)".parse().unwrap();
+        assert_eq!(x.op(&x), DynPermutation::id());
+        assert_eq!(x.order_bounded(10), Some(2));
+        assert_eq!(
+            Permutation::<5>::try_from(x.clone()),
+            Err(PermutationError::DegreeTooLarge { degree: 7, max: 5 })
+        );
+        assert_eq!(
+            Permutation::<5>::try_from(x.clone())
+                .unwrap_err()
+                .to_string(),
+            "degree 7 exceeds the maximal degree 5"
+        );
+        let s7 = Subgroup::generated_by([x, "(1 2 3 4 5 6 7)".parse().unwrap()]);
+        assert_eq!(s7.order(), 5040);
+        test_accociativity(&s7.elements()[..5]);
+    }
+
+    #[test]
+    fn construction() {
+        assert_eq!(
+            DynPermutation::try_from(vec![2, 1, 3, 4]),
+            Ok("(1 2)".parse().unwrap())
+        );
+        assert_eq!(DynPermutation::try_from(vec![2, 1, 3]).unwrap().degree(), 2);
+        assert_eq!(
+            DynPermutation::try_from(vec![2, 2]),
+            Err(PermutationError::RepeatedImage(2))
+        );
+        assert_eq!(
+            DynPermutation::try_from(vec![3, 1]),
+            Err(PermutationError::OutOfRange(3))
+        );
+        assert_eq!(
+            "(0 1)".parse::<DynPermutation>(),
+            Err(ParsePermutationError::OutOfRange(0))
+        );
+        assert_eq!(DynPermutation::id().to_string(), "()");
+    }
+
+    #[test]
+    fn huge_points() {
+        let max = DynPermutation::MAX_DEGREE;
+        let x: DynPermutation = format!("(1 {max})").parse().unwrap();
+        assert_eq!(x.degree(), max);
+        assert_eq!(
+            format!("(1 {})", max + 1).parse::<DynPermutation>(),
+            Err(ParsePermutationError::OutOfRange(max + 1))
+        );
+        assert_eq!(
+            "(1 4000000000)".parse::<DynPermutation>(),
+            Err(ParsePermutationError::OutOfRange(4000000000))
+        );
+        assert_eq!(
+            "(1 99999999999999999999999)".parse::<DynPermutation>(),
+            Err(ParsePermutationError::Overflow)
+        );
+        let mut images: Vec<usize> = (1..=max + 1).collect();
+        images.swap(0, max);
+        assert_eq!(
+            DynPermutation::try_from(images),
+            Err(PermutationError::OutOfRange(max + 1))
+        );
+        // Trailing fixed points do not count towards the degree
+        let images: Vec<usize> = (1..=max + 1).collect();
+        assert_eq!(DynPermutation::try_from(images), Ok(DynPermutation::id()));
+        assert_eq!(DynPermutation::default(), DynPermutation::id());
+    }
+
+    #[test]
+    #[should_panic(expected = "points start at 1")]
+    fn image_of_zero() {
+        DynPermutation::id().image(0);
+    }
+}
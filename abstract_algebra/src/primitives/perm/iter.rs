@@ -0,0 +1,141 @@
+use crate::{ops::Identity, primitives::perm::Permutation};
+
+/// Iterates over all permutations of `S_N` in lexicographic order of their images, starting with
+/// the identity.
+///
+/// The `k`-th permutation is the one with [`rank`](Permutation::rank) `k`.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::primitives::{Lexicographic, Permutation};
+///
+/// let all: Vec<String> = Lexicographic::<3>::new().map(|x| x.to_string()).collect();
+/// assert_eq!(all, ["()", "(2 3)", "(1 2)", "(1 2 3)", "(1 3 2)", "(1 3)"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Lexicographic<const N: usize>(Option<Permutation<N>>);
+
+impl<const N: usize> Lexicographic<N> {
+    pub fn new() -> Self {
+        Self(Some(Permutation::id()))
+    }
+}
+
+impl<const N: usize> Default for Lexicographic<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Iterator for Lexicographic<N> {
+    type Item = Permutation<N>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.0?;
+        let mut next = current.0;
+        // Find the longest non-increasing suffix, bump the element before it to the next larger
+        // one in the suffix and reverse the suffix
+        self.0 = match (1..N).rev().find(|&i| next[i - 1] < next[i]) {
+            Some(i) => {
+                let j = (i..N).rev().find(|&j| next[j] > next[i - 1]).unwrap();
+                next.swap(i - 1, j);
+                next[i..].reverse();
+                Some(Permutation(next))
+            }
+            None => None,
+        };
+        Some(current)
+    }
+}
+
+/// Iterates over all permutations of `S_N` using Heap's algorithm, starting with the identity.
+///
+/// Consecutive permutations differ by swapping two images, i.e. by composing with a
+/// transposition on the right, which makes this cheaper than [`Lexicographic`].
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::primitives::{Heap, Permutation};
+///
+/// let all: Vec<String> = Heap::<3>::new().map(|x| x.to_string()).collect();
+/// assert_eq!(all, ["()", "(1 2)", "(1 3 2)", "(2 3)", "(1 2 3)", "(1 3)"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Heap<const N: usize> {
+    current: [usize; N],
+    counters: [usize; N],
+    i: usize,
+    started: bool,
+}
+
+impl<const N: usize> Heap<N> {
+    pub fn new() -> Self {
+        Self {
+            current: Permutation::<N>::id().0,
+            counters: [0; N],
+            i: 1,
+            started: false,
+        }
+    }
+}
+
+impl<const N: usize> Default for Heap<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Iterator for Heap<N> {
+    type Item = Permutation<N>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some(Permutation(self.current));
+        }
+        while self.i < N {
+            let i = self.i;
+            if self.counters[i] < i {
+                let j = if i.is_multiple_of(2) {
+                    0
+                } else {
+                    self.counters[i]
+                };
+                self.current.swap(j, i);
+                self.counters[i] += 1;
+                self.i = 1;
+                return Some(Permutation(self.current));
+            }
+            self.counters[i] = 0;
+            self.i += 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ops::{BinOp, Invertible, Multiplication, test_enumeration};
+
+    use super::*;
+
+    #[test]
+    fn lexicographic() {
+        let elements: Vec<Permutation<5>> = Lexicographic::new().collect();
+        test_enumeration::<_, Multiplication>(&elements);
+        assert!(elements.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(Lexicographic::<0>::new().count(), 1);
+        assert_eq!(Lexicographic::<1>::new().count(), 1);
+    }
+
+    #[test]
+    fn heap() {
+        let elements: Vec<Permutation<5>> = Heap::new().collect();
+        test_enumeration::<_, Multiplication>(&elements);
+        for w in elements.windows(2) {
+            // The quotient of consecutive permutations is a transposition
+            assert_eq!(w[0].inv().op(&w[1]).cycle_type()[..2], [2, 1]);
+        }
+        assert_eq!(Heap::<0>::new().count(), 1);
+        assert_eq!(Heap::<1>::new().count(), 1);
+        assert_eq!(Heap::<2>::new().count(), 2);
+    }
+}
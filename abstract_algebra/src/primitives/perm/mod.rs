@@ -0,0 +1,357 @@
+mod cycles;
+mod dynamic;
+mod iter;
+mod parse;
+mod rank;
+
+pub use dynamic::*;
+pub use iter::*;
+pub use parse::*;
+
+use std::{error, fmt};
+
+use crate::{
+    actions::LeftAction,
+    ops::{Addition, Associativity, BinOp, Identity, Invertible, Multiplication, OperationKind},
+    primitives::CyclicNumber,
+    structures::{FiniteGroup, Homomorphism},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Permutation<const N: usize>([usize; N]);
+
+/// The error returned when an array of images is not a permutation of `1..=N`, or when a
+/// [`DynPermutation`] does not fit into `S_N`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PermutationError {
+    /// An image which is not in `1..=N`
+    OutOfRange(usize),
+    /// An image which occurs more than once
+    RepeatedImage(usize),
+    /// A permutation whose largest moved point `degree` is beyond `N = max`
+    DegreeTooLarge { degree: usize, max: usize },
+}
+
+impl fmt::Display for PermutationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange(image) => write!(f, "image {image} is out of range"),
+            Self::RepeatedImage(image) => write!(f, "image {image} occurs more than once"),
+            Self::DegreeTooLarge { degree, max } => {
+                write!(f, "degree {degree} exceeds the maximal degree {max}")
+            }
+        }
+    }
+}
+
+impl error::Error for PermutationError {}
+
+/// Validates that `value` lists the images of `1..=N` and that each of them occurs exactly once
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::perm;
+/// use abstract_algebra::ops::Identity;
+/// use abstract_algebra::primitives::{Permutation, PermutationError};
+///
+/// assert_eq!(Permutation::try_from([2, 3, 1]), Ok(perm![(1 2 3)]));
+/// assert_eq!(Permutation::try_from([1, 1, 3]), Err(PermutationError::RepeatedImage(1)));
+/// assert_eq!(Permutation::try_from([0, 1, 2]), Err(PermutationError::OutOfRange(0)));
+/// ```
+impl<const N: usize> TryFrom<[usize; N]> for Permutation<N> {
+    type Error = PermutationError;
+
+    fn try_from(value: [usize; N]) -> Result<Self, Self::Error> {
+        validate(&value)?;
+        Ok(Self(value))
+    }
+}
+
+/// Checks that `images` is a permutation of `1..=images.len()`
+fn validate(images: &[usize]) -> Result<(), PermutationError> {
+    let mut seen = vec![false; images.len()];
+    for &image in images {
+        if !(1..=images.len()).contains(&image) {
+            return Err(PermutationError::OutOfRange(image));
+        }
+        if seen[image - 1] {
+            return Err(PermutationError::RepeatedImage(image));
+        }
+        seen[image - 1] = true;
+    }
+    Ok(())
+}
+
+impl<const N: usize> Permutation<N> {
+    /// Creates the permutation mapping `i` to `images[i - 1]` without validating the images.
+    ///
+    /// The caller has to make sure that `images` is a permutation of `1..=N`, as the group
+    /// operations may panic or give meaningless results otherwise. Use [`TryFrom`] to validate
+    /// the images instead. Invalid images are only caught in debug builds.
+    pub fn new_unchecked(images: [usize; N]) -> Self {
+        debug_assert!(
+            Self::try_from(images).is_ok(),
+            "{images:?} is not a permutation"
+        );
+        Self(images)
+    }
+
+    /// The image of `point` in `1..=N` under the permutation
+    ///
+    /// # Panics
+    /// if `point` is not in `1..=N`
+    pub fn image(&self, point: usize) -> usize {
+        self.0[point - 1]
+    }
+
+    /// The image of `g` under the left regular representation of `G`, which numbers the
+    /// elements of `G` by their position in [`FiniteGroup::elements`] starting from `1` and maps
+    /// `h` to `gh`
+    ///
+    /// # Panics
+    /// if `G` does not have order `N`
+    pub fn left_regular<G, Op>(g: &G) -> Self
+    where
+        G: FiniteGroup<Op>,
+        Op: OperationKind,
+    {
+        let elements: Vec<G> = G::elements().collect();
+        assert_eq!(elements.len(), N, "the group has to have order N");
+        Self(core::array::from_fn(|i| {
+            let y = g.op(&elements[i]);
+            elements.iter().position(|h| *h == y).unwrap() + 1
+        }))
+    }
+}
+
+impl<const N: usize> Associativity<Multiplication> for Permutation<N> {}
+
+impl<const N: usize> BinOp<Multiplication> for Permutation<N> {
+    fn op(&self, y: &Self) -> Self {
+        Self(y.0.map(|i| self.0[i - 1]))
+    }
+}
+
+impl<const N: usize> Identity<Multiplication> for Permutation<N> {
+    fn id() -> Self {
+        Self(::core::array::from_fn(|i| i + 1))
+    }
+}
+
+impl<const N: usize> Invertible<Multiplication> for Permutation<N> {
+    fn inv(&self) -> Self {
+        let mut inverse = [0; N];
+        for (i, &v) in self.0.iter().enumerate() {
+            inverse[v - 1] = i + 1
+        }
+        Self(inverse)
+    }
+}
+
+impl<const N: usize> FiniteGroup<Multiplication> for Permutation<N> {
+    fn elements() -> impl Iterator<Item = Self> {
+        Lexicographic::new()
+    }
+    /// # Panics
+    /// if `N!` does not fit into a `usize`
+    fn order() -> usize {
+        (1..=N)
+            .try_fold(1usize, |order, k| order.checked_mul(k))
+            .expect("N! has to fit into a usize")
+    }
+    /// Computed as the least common multiple of the cycle lengths
+    fn element_order(&self) -> usize {
+        cycles::element_order(&self.0)
+    }
+}
+
+/// The sign homomorphism `S_N -> Z/2Z`, mapping even permutations to `0` and odd ones to `1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sign;
+
+impl<const N: usize> Homomorphism<Permutation<N>, CyclicNumber<2>, Multiplication, Addition>
+    for Sign
+{
+    fn apply(&self, x: &Permutation<N>) -> CyclicNumber<2> {
+        usize::from(!x.is_even()).into()
+    }
+}
+
+/// The natural action of `S_N` on the points `1..=N`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OnPoints;
+
+impl<const N: usize> LeftAction<Permutation<N>, usize> for OnPoints {
+    fn act(&self, g: &Permutation<N>, x: &usize) -> usize {
+        g.image(*x)
+    }
+}
+
+/// The action of `S_N` on `N`-tuples by permuting the entries, such that the entry at position
+/// `i` is moved to position `g(i)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OnEntries;
+
+impl<T: Clone, const N: usize> LeftAction<Permutation<N>, [T; N]> for OnEntries {
+    fn act(&self, g: &Permutation<N>, x: &[T; N]) -> [T; N] {
+        let g = g.inv();
+        core::array::from_fn(|j| x[g.0[j] - 1].clone())
+    }
+}
+
+#[macro_export]
+macro_rules! perm {
+    [@cycle; ()] => {{
+        $crate::primitives::Permutation::id()
+    }};
+    [@cycle; ($($elems:literal)+)] => {{
+        let cycle = [$($elems),+];
+        let mut perm = ::core::array::from_fn(|i| i + 1);
+
+        let m = cycle.len();
+        for i in 0..m {
+            let from = cycle[i % m] - 1;
+            let to = cycle[(i + 1) % m];
+            perm[from] = to;
+        }
+        $crate::primitives::Permutation::new_unchecked(perm)
+    }};
+    [ $N:literal ; $( $tt:tt )+] => {{
+        let out: $crate::primitives::Permutation::<$N> = perm![$($tt)+];
+        out
+    }};
+    [$($tt:tt)+] => {{
+        let mut y = $crate::primitives::Permutation::id();
+        $(
+            y = $crate::ops::BinOp::<$crate::ops::Multiplication>::op(&y, &perm![@cycle; $tt]);
+        )+
+        y
+    }}
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::{test_accociativity, test_finite_group},
+        structures::{Group, Monoid},
+    };
+
+    use super::*;
+
+    #[test]
+    fn perm_macro() {
+        let id = perm![5; ()];
+        assert_eq!(id, Permutation::id());
+
+        assert_eq!(perm![5; (1 2 3)(4 5)].inv(), perm![5; (1 3 2)(4 5)]);
+        assert_eq!(perm![3; (1 2 3)], perm![3; (2 3 1)]);
+        assert_eq!(perm![(1 2)(2 3)], perm![5; (1 2 3)]);
+
+        assert_eq!(perm![5; (1 2 3 4 5)].inv(), perm![(1 5 4 3 2)]);
+    }
+
+    #[test]
+    fn test() {
+        let id = perm![5; ()];
+        let a = perm![5; (1 2 3)(4 5)];
+        let b = perm![5; (1 2)];
+        let c = perm![5; (1 3 4)];
+
+        assert_eq!(a.pow(6), id);
+        assert_eq!(a.pow(2), perm![(1 3 2)]);
+        assert_eq!(a.pow(3), perm![(4 5)]);
+
+        test_accociativity(&[a, b, c]);
+    }
+
+    #[test]
+    fn validated_construction() {
+        for x in Permutation::<4>::elements() {
+            assert_eq!(Permutation::try_from(x.0), Ok(x));
+        }
+        assert_eq!(
+            Permutation::try_from([1, 2, 5, 4]),
+            Err(PermutationError::OutOfRange(5))
+        );
+        assert_eq!(
+            Permutation::try_from([3, 2, 3, 4]),
+            Err(PermutationError::RepeatedImage(3))
+        );
+        assert_eq!(
+            PermutationError::OutOfRange(5).to_string(),
+            "image 5 is out of range"
+        );
+        assert_eq!(Permutation::new_unchecked([2, 1, 3]), perm![(1 2)]);
+    }
+
+    #[test]
+    fn actions() {
+        let generators = [perm![4; (1 2)], perm![4; (1 2 3 4)]];
+        assert!(OnPoints.is_action_on(&generators, &[1, 2, 3, 4]));
+        let words: Vec<[char; 4]> = Permutation::<4>::elements()
+            .map(|g| OnEntries.act(&g, &['a', 'b', 'c', 'd']))
+            .collect();
+        assert!(OnEntries.is_action_on(&generators, &words));
+        // Moving entries along `g` agrees with moving points along `g`
+        let g = perm![4; (1 3 4)];
+        let x = OnEntries.act(&g, &[1, 2, 3, 4]);
+        for i in 1..=4 {
+            assert_eq!(x[OnPoints.act(&g, &i) - 1], i);
+        }
+    }
+
+    #[test]
+    fn conjugation() {
+        let a = perm![5; (1 2 3)];
+        let g = perm![5; (1 4)(2 5)];
+        // Conjugating a cycle relabels its points
+        assert_eq!(a.conjugate(&g), perm![(4 5 3)]);
+        assert_eq!(a.commutator(&a.pow(2)), Permutation::id());
+        assert_eq!(a.commutator(&g), a.op(&a.conjugate(&g).inv()));
+        assert_eq!(a.element_order(), 3);
+        assert_eq!(g.element_order(), 2);
+        assert_eq!(a.cycle_type(), [3, 1, 1]);
+        assert_eq!(a.conjugate(&g).cycle_type(), a.cycle_type());
+        assert_eq!(g.cycle_type(), [2, 2, 1]);
+    }
+
+    #[test]
+    fn sign() {
+        let sign = |x: &Permutation<4>| -> CyclicNumber<2> { Sign.apply(x) };
+        assert_eq!(sign(&perm![(1 2)]), 1.into());
+        assert_eq!(sign(&perm![(1 2 3)]), 0.into());
+        assert_eq!(sign(&perm![(1 2 3 4)]), 1.into());
+        assert!(
+            Homomorphism::<Permutation<4>, _, _, Addition>::is_homomorphism_on(
+                &Sign,
+                &[perm![(1 2)], perm![(1 2 3 4)]]
+            )
+        );
+        assert_eq!(
+            Homomorphism::<Permutation<4>, CyclicNumber<2>, _, Addition>::kernel(&Sign).order(),
+            12
+        );
+    }
+
+    #[test]
+    fn finite_group() {
+        test_finite_group::<Permutation<4>, _>();
+        test_finite_group::<Permutation<0>, _>();
+        assert_eq!(Permutation::<20>::order(), 2_432_902_008_176_640_000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn order_overflow() {
+        Permutation::<21>::order();
+    }
+
+    #[test]
+    fn group_action() {
+        let set = ['a', 'b', 'c', 'd', 'e'];
+        let perm = perm![5; (1 3 5)(2 4)];
+
+        assert_eq!(OnEntries.act(&perm, &set), ['e', 'd', 'a', 'b', 'c']);
+        assert!(OnEntries.is_action_on(&[perm], &[set]));
+    }
+}
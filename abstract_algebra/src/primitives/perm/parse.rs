@@ -0,0 +1,222 @@
+use std::{error, fmt, str::FromStr};
+
+use crate::primitives::Permutation;
+
+/// Writes the permutation as a product of disjoint cycles, e.g. `(1 2 3)(4 5)`, omitting fixed
+/// points, and the identity as `()`
+impl<const N: usize> fmt::Display for Permutation<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_cycles(f, &self.cycles())
+    }
+}
+
+pub(super) fn write_cycles(f: &mut fmt::Formatter<'_>, cycles: &[Vec<usize>]) -> fmt::Result {
+    if cycles.is_empty() {
+        return f.write_str("()");
+    }
+    for cycle in cycles {
+        let points: Vec<String> = cycle.iter().map(usize::to_string).collect();
+        write!(f, "({})", points.join(" "))?;
+    }
+    Ok(())
+}
+
+/// The errors which can occur when parsing a [`Permutation`] in cycle notation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePermutationError {
+    /// A character other than digits, whitespace, commas and parentheses
+    InvalidCharacter(char),
+    /// A `(` inside of a cycle, a `)` outside of a cycle or a cycle which is never closed
+    UnbalancedParentheses,
+    /// A point which is not enclosed in parentheses
+    PointOutsideCycle(usize),
+    /// A point which is not in `1..=N`, where `N` is
+    /// [`DynPermutation::MAX_DEGREE`](crate::primitives::DynPermutation::MAX_DEGREE) for
+    /// permutations of runtime degree
+    OutOfRange(usize),
+    /// A point with too many digits to fit into a `usize`
+    Overflow,
+    /// A point which occurs more than once, as the cycles have to be disjoint
+    RepeatedPoint(usize),
+}
+
+impl fmt::Display for ParsePermutationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCharacter(c) => write!(f, "invalid character {c:?}"),
+            Self::UnbalancedParentheses => f.write_str("unbalanced parentheses"),
+            Self::PointOutsideCycle(point) => write!(f, "point {point} is outside of a cycle"),
+            Self::OutOfRange(point) => write!(f, "point {point} is out of range"),
+            Self::Overflow => f.write_str("point does not fit into a usize"),
+            Self::RepeatedPoint(point) => write!(f, "point {point} occurs more than once"),
+        }
+    }
+}
+
+impl error::Error for ParsePermutationError {}
+
+/// Parses a product of disjoint cycles like `(1 2 3)(4 5)` or `(1,2,3)(4,5)`, where points
+/// which do not occur are fixed. Both `()` and the empty string denote the identity.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::perm;
+/// use abstract_algebra::ops::Identity;
+/// use abstract_algebra::primitives::{ParsePermutationError, Permutation};
+///
+/// let x: Permutation<5> = "(1 2 3)(4 5)".parse().unwrap();
+/// assert_eq!(x, perm![(1 2 3)(4 5)]);
+/// assert_eq!(x.to_string().parse(), Ok(x));
+/// assert_eq!(
+///     Permutation::<5>::try_from("(1 2)(2 3)"),
+///     Err(ParsePermutationError::RepeatedPoint(2))
+/// );
+/// assert_eq!(
+///     "(1 6)".parse::<Permutation<5>>(),
+///     Err(ParsePermutationError::OutOfRange(6))
+/// );
+/// ```
+impl<const N: usize> FromStr for Permutation<N> {
+    type Err = ParsePermutationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut images: [usize; N] = core::array::from_fn(|i| i + 1);
+        for cycle in parse_cycles(s, N)? {
+            for (i, &point) in cycle.iter().enumerate() {
+                images[point - 1] = cycle[(i + 1) % cycle.len()];
+            }
+        }
+        Ok(Self(images))
+    }
+}
+
+/// Parses a product of disjoint cycles with points in `1..=degree`
+pub(super) fn parse_cycles(
+    s: &str,
+    degree: usize,
+) -> Result<Vec<Vec<usize>>, ParsePermutationError> {
+    let mut cycles = Vec::new();
+    let mut seen = Vec::new();
+    let mut cycle: Option<Vec<usize>> = None;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '(' => {
+                if cycle.replace(Vec::new()).is_some() {
+                    return Err(ParsePermutationError::UnbalancedParentheses);
+                }
+            }
+            ')' => {
+                let points = cycle
+                    .take()
+                    .ok_or(ParsePermutationError::UnbalancedParentheses)?;
+                cycles.push(points);
+            }
+            '0'..='9' => {
+                let mut point = c as usize - '0' as usize;
+                while let Some(d) = chars.peek().and_then(|d| d.to_digit(10)) {
+                    chars.next();
+                    point = point
+                        .checked_mul(10)
+                        .and_then(|p| p.checked_add(d as usize))
+                        .ok_or(ParsePermutationError::Overflow)?;
+                }
+                let points = cycle
+                    .as_mut()
+                    .ok_or(ParsePermutationError::PointOutsideCycle(point))?;
+                if point == 0 || point > degree {
+                    return Err(ParsePermutationError::OutOfRange(point));
+                }
+                if seen.contains(&point) {
+                    return Err(ParsePermutationError::RepeatedPoint(point));
+                }
+                seen.push(point);
+                points.push(point);
+            }
+            ',' => {}
+            c if c.is_whitespace() => {}
+            c => return Err(ParsePermutationError::InvalidCharacter(c)),
+        }
+    }
+    if cycle.is_some() {
+        return Err(ParsePermutationError::UnbalancedParentheses);
+    }
+    Ok(cycles)
+}
+
+impl<const N: usize> TryFrom<&str> for Permutation<N> {
+    type Error = ParsePermutationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ops::Identity, perm, structures::FiniteGroup};
+
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Permutation::<4>::id().to_string(), "()");
+        assert_eq!(perm![5; (5 4)(3 1 2)].to_string(), "(1 2 3)(4 5)");
+        assert_eq!(perm![12; (10 11 12)].to_string(), "(10 11 12)");
+        for x in Permutation::<5>::elements() {
+            assert_eq!(x.to_string().parse(), Ok(x));
+        }
+    }
+
+    #[test]
+    fn parse() {
+        type P = Permutation<12>;
+        assert_eq!("".parse(), Ok(P::id()));
+        assert_eq!(" ( ) (7) ".parse(), Ok(P::id()));
+        assert_eq!("(1, 12, 3)(4,5)".parse(), Ok(perm![12; (1 12 3)(4 5)]));
+        assert_eq!(
+            "(1 2".parse::<P>(),
+            Err(ParsePermutationError::UnbalancedParentheses)
+        );
+        assert_eq!(
+            "(1 (2 3))".parse::<P>(),
+            Err(ParsePermutationError::UnbalancedParentheses)
+        );
+        assert_eq!(
+            "(1 2))".parse::<P>(),
+            Err(ParsePermutationError::UnbalancedParentheses)
+        );
+        assert_eq!(
+            "1 2".parse::<P>(),
+            Err(ParsePermutationError::PointOutsideCycle(1))
+        );
+        assert_eq!(
+            "(1 2; 3)".parse::<P>(),
+            Err(ParsePermutationError::InvalidCharacter(';'))
+        );
+        assert_eq!(
+            "(0 1)".parse::<P>(),
+            Err(ParsePermutationError::OutOfRange(0))
+        );
+        assert_eq!(
+            "(99999999999999999999999 1)".parse::<P>(),
+            Err(ParsePermutationError::Overflow)
+        );
+        assert_eq!(
+            "(18446744073709551616 1)".parse::<P>(),
+            Err(ParsePermutationError::Overflow)
+        );
+        assert_eq!(
+            "(1 18446744073709551615)".parse::<P>(),
+            Err(ParsePermutationError::OutOfRange(usize::MAX))
+        );
+        assert_eq!(
+            "(1 2 1)".parse::<P>(),
+            Err(ParsePermutationError::RepeatedPoint(1))
+        );
+        assert_eq!(
+            ParsePermutationError::RepeatedPoint(1).to_string(),
+            "point 1 occurs more than once"
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use crate::primitives::perm::Permutation;
+
+impl<const N: usize> Permutation<N> {
+    /// The Lehmer code, whose `i`-th entry is the number of `j > i` with `x(j) < x(i)`
+    pub fn lehmer_code(&self) -> [usize; N] {
+        core::array::from_fn(|i| (i + 1..N).filter(|&j| self.0[j] < self.0[i]).count())
+    }
+
+    /// The position in the lexicographic order of all permutations of `S_N`, which is a
+    /// bijection onto `0..N!`
+    ///
+    /// # Panics
+    /// if `N!` does not fit into a `usize`
+    ///
+    /// # Example
+    /// ```rust
+    /// use abstract_algebra::perm;
+    /// use abstract_algebra::ops::Identity;
+    /// use abstract_algebra::primitives::Permutation;
+    ///
+    /// let x = perm![4; (1 3)(2 4)];
+    /// assert_eq!(x.rank(), 16);
+    /// assert_eq!(Permutation::unrank(16), Some(x));
+    /// assert_eq!(Permutation::<4>::unrank(24), None);
+    /// ```
+    pub fn rank(&self) -> usize {
+        let mut rank: usize = 0;
+        for (i, d) in self.lehmer_code().into_iter().enumerate() {
+            rank = rank
+                .checked_mul(N - i)
+                .and_then(|r| r.checked_add(d))
+                .expect("N! has to fit into a usize");
+        }
+        rank
+    }
+
+    /// The permutation with the given [`rank`](Self::rank), or `None` if `rank >= N!`
+    pub fn unrank(mut rank: usize) -> Option<Self> {
+        let mut code = [0; N];
+        for i in (0..N).rev() {
+            let base = N - i;
+            code[i] = rank % base;
+            rank /= base;
+        }
+        if rank > 0 {
+            return None;
+        }
+        let mut remaining: Vec<usize> = (1..=N).collect();
+        Some(Self(code.map(|d| remaining.remove(d))))
+    }
+
+    /// The number of inversions, i.e. pairs `i < j` with `x(i) > x(j)`
+    pub fn inversions(&self) -> usize {
+        self.lehmer_code().iter().sum()
+    }
+
+    /// The descent set, i.e. the positions `i` in `1..N` with `x(i) > x(i + 1)`
+    pub fn descents(&self) -> Vec<usize> {
+        (1..N)
+            .filter(|&i| self.image(i) > self.image(i + 1))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::{BinOp, Identity},
+        perm,
+        primitives::{Heap, Lexicographic},
+        structures::FiniteGroup,
+    };
+
+    use super::*;
+
+    #[test]
+    fn rank_is_lexicographic_position() {
+        for (k, x) in Lexicographic::<5>::new().enumerate() {
+            assert_eq!(x.rank(), k);
+            assert_eq!(Permutation::unrank(k), Some(x));
+        }
+        assert_eq!(Permutation::<5>::unrank(120), None);
+        assert_eq!(
+            Permutation::<0>::unrank(0),
+            Some(Permutation::new_unchecked([]))
+        );
+        let mut ranks: Vec<usize> = Heap::<4>::new().map(|x| x.rank()).collect();
+        ranks.sort();
+        assert_eq!(ranks, (0..24).collect::<Vec<_>>());
+        let last = Permutation::<20>::new_unchecked(core::array::from_fn(|i| 20 - i));
+        assert_eq!(last.rank(), Permutation::<20>::order() - 1);
+    }
+
+    #[test]
+    fn inversions_and_descents() {
+        let x = Permutation::<5>::try_from([3, 1, 5, 4, 2]).unwrap();
+        assert_eq!(x.lehmer_code(), [2, 0, 2, 1, 0]);
+        assert_eq!(x.inversions(), 5);
+        assert_eq!(x.descents(), [1, 3, 4]);
+        for x in Permutation::<4>::elements() {
+            assert_eq!(x.inversions() % 2 == 0, x.is_even());
+        }
+        // Composing with an adjacent transposition on the right changes the inversions by one
+        let y = x.op(&perm![(2 3)]);
+        assert_eq!(y.inversions(), 6);
+        assert!(Permutation::<4>::elements().all(|x| x.descents().is_empty() == (x.rank() == 0)));
+    }
+}
@@ -0,0 +1,156 @@
+//! Direct products, where tuples and arrays of algebraic structures inherit the operations
+//! componentwise.
+//!
+//! # Example
+//! ```rust
+//! use abstract_algebra::ops::{Addition, BinOp};
+//! use abstract_algebra::primitives::{CyclicNumber, Integer};
+//! use abstract_algebra::structures::{AbelianGroup, Ring};
+//!
+//! fn _f() -> impl AbelianGroup<Addition> {
+//!     (CyclicNumber::<2>::from(1), CyclicNumber::<3>::from(2))
+//! }
+//! let x = (Integer::new(2), Integer::new(3));
+//! assert_eq!(x.mul(&x).add(&Ring::one()), (Integer::new(5), Integer::new(10)));
+//! ```
+use crate::{
+    ops::{Associativity, BinOp, Commutativity, Identity, Invertible, OperationKind},
+    structures::FiniteGroup,
+};
+
+macro_rules! impl_tuple {
+    ($($T:ident . $i:tt),+) => {
+        impl<Op: OperationKind, $($T: BinOp<Op>),+> BinOp<Op> for ($($T,)+) {
+            fn op(&self, rhs: &Self) -> Self {
+                ($(self.$i.op(&rhs.$i),)+)
+            }
+        }
+
+        impl<Op: OperationKind, $($T: Identity<Op>),+> Identity<Op> for ($($T,)+) {
+            fn id() -> Self {
+                ($($T::id(),)+)
+            }
+        }
+
+        impl<Op: OperationKind, $($T: Invertible<Op>),+> Invertible<Op> for ($($T,)+) {
+            fn inv(&self) -> Self {
+                ($(self.$i.inv(),)+)
+            }
+        }
+
+        impl<Op: OperationKind, $($T: Associativity<Op>),+> Associativity<Op> for ($($T,)+) {}
+        impl<Op: OperationKind, $($T: Commutativity<Op>),+> Commutativity<Op> for ($($T,)+) {}
+
+        /// Lists the elements with the first component changing fastest
+        impl<Op: OperationKind, $($T: FiniteGroup<Op>),+> FiniteGroup<Op> for ($($T,)+) {
+            fn elements() -> impl Iterator<Item = Self> {
+                let parts = ($($T::elements().collect::<Vec<_>>(),)+);
+                let order = 1 $(* parts.$i.len())+;
+                (0..order).map(move |mut k| {
+                    ($({
+                        let x = parts.$i[k % parts.$i.len()].clone();
+                        k /= parts.$i.len();
+                        x
+                    },)+)
+                })
+            }
+            fn order() -> usize {
+                1 $(* $T::order())+
+            }
+        }
+    };
+}
+
+impl_tuple!(A.0, B.1);
+impl_tuple!(A.0, B.1, C.2);
+impl_tuple!(A.0, B.1, C.2, D.3);
+
+impl<Op: OperationKind, T: BinOp<Op>, const K: usize> BinOp<Op> for [T; K] {
+    fn op(&self, rhs: &Self) -> Self {
+        core::array::from_fn(|i| self[i].op(&rhs[i]))
+    }
+}
+
+impl<Op: OperationKind, T: Identity<Op>, const K: usize> Identity<Op> for [T; K] {
+    fn id() -> Self {
+        core::array::from_fn(|_| T::id())
+    }
+}
+
+impl<Op: OperationKind, T: Invertible<Op>, const K: usize> Invertible<Op> for [T; K] {
+    fn inv(&self) -> Self {
+        core::array::from_fn(|i| self[i].inv())
+    }
+}
+
+impl<Op: OperationKind, T: Associativity<Op>, const K: usize> Associativity<Op> for [T; K] {}
+impl<Op: OperationKind, T: Commutativity<Op>, const K: usize> Commutativity<Op> for [T; K] {}
+
+/// Lists the elements with the first component changing fastest
+impl<Op: OperationKind, T: FiniteGroup<Op>, const K: usize> FiniteGroup<Op> for [T; K] {
+    fn elements() -> impl Iterator<Item = Self> {
+        let elements: Vec<T> = T::elements().collect();
+        let order = elements.len().pow(K as u32);
+        (0..order).map(move |mut k| {
+            core::array::from_fn(|_| {
+                let x = elements[k % elements.len()].clone();
+                k /= elements.len();
+                x
+            })
+        })
+    }
+    fn order() -> usize {
+        T::order().pow(K as u32)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::{Addition, Multiplication, test_finite_group},
+        primitives::{CyclicNumber, DihedralElement, Integer, Permutation},
+        structures::{Group, Ring, is_isomorphic},
+    };
+
+    use super::*;
+
+    #[test]
+    fn direct_product_of_groups() {
+        type C2xC3 = (CyclicNumber<2>, CyclicNumber<3>);
+        let x: C2xC3 = (1.into(), 1.into());
+        assert_eq!(Group::<Addition>::order_bounded(&x, 10), Some(6));
+        assert_eq!(<C2xC3 as FiniteGroup<Addition>>::order(), 6);
+        assert!(is_isomorphic::<C2xC3, CyclicNumber<6>, Addition, Addition>());
+        assert!(!is_isomorphic::<
+            (CyclicNumber<2>, CyclicNumber<2>),
+            CyclicNumber<4>,
+            Addition,
+            Addition,
+        >());
+
+        type G = (Permutation<3>, DihedralElement<2>, Permutation<2>);
+        test_finite_group::<G, Multiplication>();
+    }
+
+    #[test]
+    fn direct_product_of_rings() {
+        let x = (Integer::new(2), Integer::new(-1));
+        let y = (Integer::new(3), Integer::new(4));
+        assert_eq!(x.mul(&y), (Integer::new(6), Integer::new(-4)));
+        assert_eq!(x.add(&y).neg(), (Integer::new(-5), Integer::new(-3)));
+        assert_eq!(
+            <(Integer, Integer)>::one(),
+            (Integer::new(1), Integer::new(1))
+        );
+    }
+
+    #[test]
+    fn arrays() {
+        type V = [CyclicNumber<2>; 3];
+        test_finite_group::<V, Addition>();
+        assert_eq!(<V as FiniteGroup<Addition>>::order(), 8);
+        for x in <V as FiniteGroup<Addition>>::elements() {
+            assert!(Group::<Addition>::order_bounded(&x, 2).is_some());
+        }
+    }
+}
@@ -22,11 +22,11 @@ impl<T: Copy + Eq> Word<T> {
     fn reduce(v: Vec<Alphabet<T>>) -> Vec<Alphabet<T>> {
         let mut reduced = Vec::new();
         for sym in v.into_iter() {
-            if let Some(last) = reduced.last() {
-                if sym.inv() == *last {
-                    reduced.pop();
-                    continue;
-                }
+            if let Some(last) = reduced.last()
+                && sym.inv() == *last
+            {
+                reduced.pop();
+                continue;
             }
             reduced.push(sym);
         }
@@ -110,3 +110,26 @@ macro_rules! word {
         word![ ( ($tt) ) $($rest)*]
     }}
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::Identity,
+        primitives::Word,
+        structures::{Group, Monoid},
+    };
+
+    #[test]
+    fn pow() {
+        let x = word!['a' 'b'];
+        assert_eq!(x.pow(3), word!['a' 'b' 'a' 'b' 'a' 'b']);
+        assert_eq!(x.powi(-2), word!['b'- 'a'- 'b'- 'a'-]);
+        assert_eq!(x.powi(0), Word::id());
+    }
+
+    #[test]
+    fn order() {
+        assert_eq!(word!['a' 'b'].order_bounded(1000), None);
+        assert_eq!(Word::<char>::id().order_bounded(1000), Some(1));
+    }
+}
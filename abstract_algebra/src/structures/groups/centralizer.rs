@@ -0,0 +1,69 @@
+use crate::{
+    ops::OperationKind,
+    structures::{FiniteGroup, Subgroup},
+};
+
+impl<G: FiniteGroup<Op>, Op: OperationKind> Subgroup<G, Op> {
+    /// The center `Z(G) = { g | gx = xg for all x in G }`
+    ///
+    /// # Example
+    /// ```rust
+    /// use abstract_algebra::primitives::DihedralElement;
+    /// use abstract_algebra::structures::Subgroup;
+    ///
+    /// assert_eq!(Subgroup::<DihedralElement<4>>::center().order(), 2);
+    /// assert_eq!(Subgroup::<DihedralElement<5>>::center().order(), 1);
+    /// ```
+    pub fn center() -> Self {
+        Self::whole().centralizer()
+    }
+
+    /// The centralizer `C_G(x) = { g | gx = xg }` of the element `x`
+    pub fn centralizer_of(x: &G) -> Self {
+        Self::from_elements(G::elements().filter(|g| g.op(x) == x.op(g)))
+    }
+
+    /// The centralizer `C_G(H)` of elements commuting with every element of `self`
+    pub fn centralizer(&self) -> Self {
+        Self::from_elements(
+            G::elements().filter(|g| self.generators().iter().all(|h| g.op(h) == h.op(g))),
+        )
+    }
+
+    /// The normalizer `N_G(H) = { g | gHg^-1 = H }`, the largest subgroup of `G` in which `self`
+    /// is normal
+    pub fn normalizer(&self) -> Self {
+        Self::from_elements(G::elements().filter(|g| {
+            self.generators()
+                .iter()
+                .all(|h| self.contains(&h.conjugate(g)))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::{Addition, Identity},
+        perm,
+        primitives::{CyclicNumber, Permutation},
+    };
+
+    use super::*;
+
+    #[test]
+    fn centralizers_and_normalizers() {
+        assert!(Subgroup::<Permutation<4>>::center().is_trivial());
+        assert_eq!(Subgroup::<CyclicNumber<6>, Addition>::center().order(), 6);
+
+        let x = perm![4; (1 2)];
+        let c = Subgroup::centralizer_of(&x);
+        assert_eq!(c, Subgroup::generated_by([perm![(1 2)], perm![(3 4)]]));
+        assert_eq!(c.index(), x.conjugacy_class().len());
+
+        let h: Subgroup<Permutation<4>> = Subgroup::generated_by([perm![(1 2 3 4)]]);
+        assert_eq!(h.normalizer().order(), 8);
+        assert_eq!(h.centralizer(), h);
+        assert!(Subgroup::<Permutation<4>>::whole().normalizer().is_normal());
+    }
+}
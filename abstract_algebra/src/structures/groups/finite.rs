@@ -0,0 +1,95 @@
+use crate::{ops::OperationKind, structures::Group};
+
+/// A [`Group`] with finitely many elements which can all be listed.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::ops::Multiplication;
+/// use abstract_algebra::primitives::Permutation;
+/// use abstract_algebra::structures::FiniteGroup;
+///
+/// assert_eq!(<Permutation<4> as FiniteGroup<Multiplication>>::order(), 24);
+/// assert_eq!(<Permutation<4> as FiniteGroup<Multiplication>>::elements().count(), 24);
+/// ```
+pub trait FiniteGroup<Op: OperationKind>: Group<Op> {
+    /// Iterates over every element of the group exactly once, starting with the identity.
+    fn elements() -> impl Iterator<Item = Self>;
+
+    /// The number of elements of the group
+    fn order() -> usize {
+        Self::elements().count()
+    }
+
+    /// The order of the element `self`, which always divides the order of the group
+    fn element_order(&self) -> usize {
+        self.order_bounded(Self::order())
+            .expect("the order of an element divides the order of the group")
+    }
+
+    /// The conjugacy class `{ g * self * g^-1 | g in G }` of `self`
+    fn conjugacy_class(&self) -> Vec<Self> {
+        let mut class: Vec<Self> = Vec::new();
+        for g in Self::elements() {
+            let x = self.conjugate(&g);
+            if !class.contains(&x) {
+                class.push(x);
+            }
+        }
+        class
+    }
+
+    /// All conjugacy classes of the group, starting with the class of the identity
+    fn conjugacy_classes() -> Vec<Vec<Self>> {
+        let mut classes: Vec<Vec<Self>> = Vec::new();
+        for x in Self::elements() {
+            if !classes.iter().any(|c| c.contains(&x)) {
+                classes.push(x.conjugacy_class());
+            }
+        }
+        classes
+    }
+
+    /// The sizes of the conjugacy classes in ascending order, such that the classes of the
+    /// central elements come first and the sizes sum up to the order of the group
+    ///
+    /// # Example
+    /// ```rust
+    /// use abstract_algebra::ops::Multiplication;
+    /// use abstract_algebra::primitives::Permutation;
+    /// use abstract_algebra::structures::FiniteGroup;
+    ///
+    /// let equation = <Permutation<4> as FiniteGroup<Multiplication>>::class_equation();
+    /// assert_eq!(equation, vec![1, 3, 6, 6, 8]);
+    /// ```
+    fn class_equation() -> Vec<usize> {
+        let mut sizes: Vec<usize> = Self::conjugacy_classes().iter().map(Vec::len).collect();
+        sizes.sort();
+        sizes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::{Addition, Identity, Multiplication},
+        perm,
+        primitives::{CyclicNumber, DihedralElement, Permutation},
+    };
+
+    use super::*;
+
+    #[test]
+    fn conjugacy_classes() {
+        let classes = <Permutation<3> as FiniteGroup<Multiplication>>::conjugacy_classes();
+        assert_eq!(classes.len(), 3);
+        assert_eq!(classes[0], vec![Permutation::id()]);
+        assert_eq!(perm![3; (1 2)].conjugacy_class().len(), 3);
+
+        assert_eq!(DihedralElement::<5>::class_equation(), vec![1, 2, 2, 5]);
+        assert_eq!(DihedralElement::<4>::class_equation(), vec![1, 1, 2, 2, 2]);
+        assert_eq!(
+            <CyclicNumber<6> as FiniteGroup<Addition>>::class_equation(),
+            vec![1; 6]
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use std::marker::PhantomData;
+
+use crate::{
+    ops::{Multiplication, OperationKind},
+    structures::{FiniteGroup, Group, Subgroup},
+    utils::orbit,
+};
+
+/// A group homomorphism `φ: G -> H`, i.e. a map with `φ(xy) = φ(x)φ(y)`.
+///
+/// Any closure `Fn(&G) -> H` can be used as a homomorphism; whether it actually is one can be
+/// checked with [`is_homomorphism_on`](Homomorphism::is_homomorphism_on).
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::ops::Addition;
+/// use abstract_algebra::primitives::{CyclicNumber, Integer, Reduction};
+/// use abstract_algebra::structures::{Homomorphism, Ring};
+///
+/// let reduction: &dyn Homomorphism<Integer, CyclicNumber<5>, Addition, Addition> = &Reduction;
+/// assert_eq!(reduction.apply(&Integer::new(-3)), 2.into());
+///
+/// let double = |x: &CyclicNumber<6>| x.add(x);
+/// let kernel = Homomorphism::<_, _, Addition, Addition>::kernel(&double);
+/// assert_eq!(kernel.order(), 2);
+/// ```
+pub trait Homomorphism<G, H, OpG = Multiplication, OpH = Multiplication>
+where
+    G: Group<OpG>,
+    H: Group<OpH>,
+    OpG: OperationKind,
+    OpH: OperationKind,
+{
+    fn apply(&self, x: &G) -> H;
+
+    /// Verifies the homomorphism property on the subgroup generated by `generators` by checking
+    /// `φ(xg) = φ(x)φ(g)` for every element `x` and every generator `g`
+    fn is_homomorphism_on(&self, generators: &[G]) -> bool {
+        let domain = Subgroup::<G, OpG>::generated_by(generators.to_vec());
+        domain.elements().iter().all(|x| {
+            let image = self.apply(x);
+            generators
+                .iter()
+                .all(|g| self.apply(&x.op(g)) == image.op(&self.apply(g)))
+        })
+    }
+
+    /// The kernel `{ x in domain | φ(x) = 1 }`, which is a normal subgroup of `domain`
+    fn kernel_in(&self, domain: &Subgroup<G, OpG>) -> Subgroup<G, OpG> {
+        let id = H::id();
+        Subgroup::from_elements(
+            domain
+                .elements()
+                .iter()
+                .filter(|x| self.apply(x) == id)
+                .cloned(),
+        )
+    }
+
+    /// The image `φ(domain)`, generated by the images of the generators of `domain`
+    fn image_of(&self, domain: &Subgroup<G, OpG>) -> Subgroup<H, OpH> {
+        Subgroup::generated_by(domain.generators().iter().map(|x| self.apply(x)))
+    }
+
+    /// The kernel of `φ` on all of `G`
+    fn kernel(&self) -> Subgroup<G, OpG>
+    where
+        G: FiniteGroup<OpG>,
+    {
+        self.kernel_in(&Subgroup::whole())
+    }
+
+    /// The image `φ(G)`
+    fn image(&self) -> Subgroup<H, OpH>
+    where
+        G: FiniteGroup<OpG>,
+    {
+        Subgroup::from_elements(G::elements().map(|x| self.apply(&x)))
+    }
+}
+
+impl<G, H, OpG, OpH, F> Homomorphism<G, H, OpG, OpH> for F
+where
+    G: Group<OpG>,
+    H: Group<OpH>,
+    OpG: OperationKind,
+    OpH: OperationKind,
+    F: Fn(&G) -> H,
+{
+    fn apply(&self, x: &G) -> H {
+        self(x)
+    }
+}
+
+/// A homomorphism defined by the images of a set of generators.
+///
+/// The images of all other elements of the generated subgroup are computed on creation, which
+/// also verifies that the assignment extends to a well-defined homomorphism.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::perm;
+/// use abstract_algebra::ops::{Addition, Identity, Multiplication};
+/// use abstract_algebra::primitives::{CyclicNumber, Permutation};
+/// use abstract_algebra::structures::{GeneratorHomomorphism, Homomorphism};
+///
+/// let sign = GeneratorHomomorphism::<Permutation<3>, CyclicNumber<2>, Multiplication, Addition>::new([
+///     (perm![(1 2)], 1.into()),
+///     (perm![(1 2 3)], 0.into()),
+/// ])
+/// .unwrap();
+/// assert_eq!(sign.apply(&perm![(1 3)]), 1.into());
+/// assert_eq!(sign.kernel_in(&sign.domain()).order(), 3);
+///
+/// // (1 2 3) has order 3 and can't be mapped to an element of order 2
+/// assert!(GeneratorHomomorphism::<Permutation<3>, CyclicNumber<2>, Multiplication, Addition>::new([
+///     (perm![(1 2 3)], 1.into()),
+/// ])
+/// .is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct GeneratorHomomorphism<G, H, OpG = Multiplication, OpH = Multiplication>
+where
+    G: Group<OpG>,
+    H: Group<OpH>,
+    OpG: OperationKind,
+    OpH: OperationKind,
+{
+    generators: Vec<G>,
+    table: Vec<(G, H)>,
+    _ops: PhantomData<(OpG, OpH)>,
+}
+
+impl<G, H, OpG, OpH> GeneratorHomomorphism<G, H, OpG, OpH>
+where
+    G: Group<OpG>,
+    H: Group<OpH>,
+    OpG: OperationKind,
+    OpH: OperationKind,
+{
+    /// Creates the homomorphism mapping each generator to its given image, or `None` if that
+    /// assignment does not extend to a homomorphism
+    ///
+    /// # Note
+    /// See [`Subgroup::generated_by`] for infinite groups
+    pub fn new(images: impl IntoIterator<Item = (G, H)>) -> Option<Self> {
+        let (generators, targets): (Vec<G>, Vec<H>) = images.into_iter().unzip();
+        let (elements, edges) = orbit(G::id(), generators.len(), |k, x| x.op(&generators[k]));
+        // The images are determined along the Schreier tree, and the assignment extends to a
+        // homomorphism iff they are compatible with every other edge of the Cayley graph as well
+        let mut images: Vec<H> = Vec::with_capacity(elements.len());
+        for edge in &edges {
+            images.push(match edge {
+                Some((k, parent)) => images[*parent].op(&targets[*k]),
+                None => H::id(),
+            });
+        }
+        for (i, x) in elements.iter().enumerate() {
+            for (g, h) in generators.iter().zip(&targets) {
+                let j = elements.iter().position(|y| *y == x.op(g)).unwrap();
+                if images[j] != images[i].op(h) {
+                    return None;
+                }
+            }
+        }
+        Some(Self {
+            generators,
+            table: elements.into_iter().zip(images).collect(),
+            _ops: PhantomData,
+        })
+    }
+
+    /// The subgroup generated by the generators, on which the homomorphism is defined
+    pub fn domain(&self) -> Subgroup<G, OpG> {
+        Subgroup::generated_by(self.generators.clone())
+    }
+}
+
+impl<G, H, OpG, OpH> Homomorphism<G, H, OpG, OpH> for GeneratorHomomorphism<G, H, OpG, OpH>
+where
+    G: Group<OpG>,
+    H: Group<OpH>,
+    OpG: OperationKind,
+    OpH: OperationKind,
+{
+    /// # Panics
+    /// if `x` is not in the [domain](GeneratorHomomorphism::domain)
+    fn apply(&self, x: &G) -> H {
+        self.table
+            .iter()
+            .find(|(y, _)| y == x)
+            .map(|(_, image)| image.clone())
+            .expect("element is not in the domain of the homomorphism")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::{Addition, BinOp, Identity},
+        perm,
+        primitives::{CyclicNumber, Permutation},
+        structures::Monoid,
+    };
+
+    use super::*;
+
+    #[test]
+    fn closure_homomorphism() {
+        let phi = |x: &CyclicNumber<12>| Monoid::<Addition>::pow(x, 3);
+        assert!(Homomorphism::<_, _, Addition, Addition>::is_homomorphism_on(&phi, &[1.into()]));
+        let kernel = Homomorphism::<_, _, Addition, Addition>::kernel(&phi);
+        let image = Homomorphism::<_, _, Addition, Addition>::image(&phi);
+        assert_eq!(kernel.order(), 3);
+        assert_eq!(image.order(), 4);
+
+        let square = |x: &Permutation<3>| x.op(x);
+        assert!(
+            !Homomorphism::<_, _, Multiplication, Multiplication>::is_homomorphism_on(
+                &square,
+                &[perm![(1 2)], perm![(2 3)]]
+            )
+        );
+    }
+
+    #[test]
+    fn generator_homomorphism() {
+        // S_3 -> S_3 given by conjugation with (1 2)
+        let g = perm![3; (1 2)];
+        let phi = GeneratorHomomorphism::<Permutation<3>, Permutation<3>>::new([
+            (perm![(1 2)], perm![(1 2)].conjugate(&g)),
+            (perm![(1 2 3)], perm![(1 2 3)].conjugate(&g)),
+        ])
+        .unwrap();
+        assert!(phi.kernel().is_trivial());
+        assert_eq!(phi.image().order(), 6);
+        for x in Permutation::<3>::elements() {
+            assert_eq!(phi.apply(&x), x.conjugate(&g));
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    ops::OperationKind,
+    structures::{FiniteGroup, GeneratorHomomorphism, Homomorphism, Subgroup},
+};
+
+/// Searches for an isomorphism `G -> H` between two finite groups.
+///
+/// Returns `None` if the groups are not isomorphic. Each element is classified by its order and
+/// the size of its conjugacy class, which both have to be preserved; groups where these
+/// invariants don't match are rejected without searching, and otherwise only images with
+/// matching invariants are tried for the generators of `G`.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::ops::{Addition, Multiplication};
+/// use abstract_algebra::primitives::{CyclicNumber, DihedralElement, Permutation};
+/// use abstract_algebra::structures::{Homomorphism, find_isomorphism};
+///
+/// let phi = find_isomorphism::<DihedralElement<3>, Permutation<3>, Multiplication, Multiplication>()
+///     .expect("D_3 and S_3 are isomorphic");
+/// assert!(phi.kernel().is_trivial());
+///
+/// assert!(find_isomorphism::<CyclicNumber<6>, Permutation<3>, Addition, Multiplication>().is_none());
+/// ```
+pub fn find_isomorphism<G, H, OpG, OpH>() -> Option<GeneratorHomomorphism<G, H, OpG, OpH>>
+where
+    G: FiniteGroup<OpG>,
+    H: FiniteGroup<OpH>,
+    OpG: OperationKind,
+    OpH: OperationKind,
+{
+    if G::order() != H::order() {
+        return None;
+    }
+    let source = invariants::<G, OpG>();
+    let target = invariants::<H, OpH>();
+
+    let mut source_stats: Vec<_> = source.iter().map(|(_, i)| *i).collect();
+    let mut target_stats: Vec<_> = target.iter().map(|(_, i)| *i).collect();
+    source_stats.sort();
+    target_stats.sort();
+    if source_stats != target_stats {
+        return None;
+    }
+
+    // Prefer generators of large order, which tend to give small generating sets
+    let mut elements = source;
+    elements.sort_by_key(|(_, (order, _))| std::cmp::Reverse(*order));
+    let generators = Subgroup::<G, OpG>::from_elements(elements.iter().map(|(x, _)| x.clone()))
+        .generators()
+        .to_vec();
+
+    let candidates: Vec<Vec<H>> = generators
+        .iter()
+        .map(|g| {
+            let invariant = elements.iter().find(|(x, _)| x == g).unwrap().1;
+            target
+                .iter()
+                .filter(|(_, i)| *i == invariant)
+                .map(|(y, _)| y.clone())
+                .collect()
+        })
+        .collect();
+
+    search(&generators, &candidates, &mut Vec::new())
+}
+
+/// Whether the finite groups `G` and `H` are isomorphic, see [`find_isomorphism`]
+pub fn is_isomorphic<G, H, OpG, OpH>() -> bool
+where
+    G: FiniteGroup<OpG>,
+    H: FiniteGroup<OpH>,
+    OpG: OperationKind,
+    OpH: OperationKind,
+{
+    find_isomorphism::<G, H, OpG, OpH>().is_some()
+}
+
+/// Pairs every element with its order and the size of its conjugacy class
+fn invariants<G: FiniteGroup<Op>, Op: OperationKind>() -> Vec<(G, (usize, usize))> {
+    G::conjugacy_classes()
+        .into_iter()
+        .flat_map(|class| {
+            let size = class.len();
+            class.into_iter().map(move |x| {
+                let order = x.element_order();
+                (x, (order, size))
+            })
+        })
+        .collect()
+}
+
+/// Backtracking search assigning images to the generators one after another, discarding partial
+/// assignments that already fail to extend to a homomorphism
+fn search<G, H, OpG, OpH>(
+    generators: &[G],
+    candidates: &[Vec<H>],
+    images: &mut Vec<H>,
+) -> Option<GeneratorHomomorphism<G, H, OpG, OpH>>
+where
+    G: FiniteGroup<OpG>,
+    H: FiniteGroup<OpH>,
+    OpG: OperationKind,
+    OpH: OperationKind,
+{
+    let k = images.len();
+    let phi = GeneratorHomomorphism::new(generators[..k].iter().cloned().zip(images.clone()))?;
+    if !phi.kernel_in(&phi.domain()).is_trivial() {
+        return None;
+    }
+    if k == generators.len() {
+        return Some(phi);
+    }
+    for y in &candidates[k] {
+        images.push(y.clone());
+        let found = search(generators, candidates, images);
+        images.pop();
+        if found.is_some() {
+            return found;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::{Addition, BinOp, Multiplication},
+        primitives::{CyclicNumber, DihedralElement, Permutation},
+    };
+
+    use super::*;
+
+    #[test]
+    fn isomorphic_groups() {
+        let phi = find_isomorphism::<DihedralElement<3>, Permutation<3>, _, _>().unwrap();
+        let elements: Vec<_> = DihedralElement::<3>::elements().collect();
+        for x in &elements {
+            for y in &elements {
+                assert_eq!(phi.apply(&x.op(y)), phi.apply(x).op(&phi.apply(y)));
+            }
+        }
+        assert_eq!(phi.image().order(), 6);
+
+        assert!(is_isomorphic::<DihedralElement<4>, DihedralElement<4>, _, _>());
+        assert!(is_isomorphic::<
+            DihedralElement<1>,
+            CyclicNumber<2>,
+            Multiplication,
+            Addition,
+        >());
+    }
+
+    #[test]
+    fn non_isomorphic_groups() {
+        // Same order, but S_4 has trivial center while D_12 doesn't
+        assert!(!is_isomorphic::<DihedralElement<12>, Permutation<4>, _, _>());
+        assert!(!is_isomorphic::<
+            CyclicNumber<8>,
+            DihedralElement<4>,
+            Addition,
+            Multiplication,
+        >());
+        assert!(!is_isomorphic::<
+            CyclicNumber<6>,
+            Permutation<3>,
+            Addition,
+            Multiplication,
+        >());
+    }
+}
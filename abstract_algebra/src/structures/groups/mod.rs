@@ -0,0 +1,18 @@
+mod centralizer;
+mod finite;
+mod homomorphism;
+mod isomorphism;
+mod quotient;
+mod semidirect;
+mod stabilizer_chain;
+mod subgroup;
+mod wreath;
+
+pub use finite::*;
+pub use homomorphism::*;
+pub use isomorphism::*;
+pub use quotient::*;
+pub use semidirect::*;
+pub use stabilizer_chain::*;
+pub use subgroup::*;
+pub use wreath::*;
@@ -0,0 +1,347 @@
+use std::{fmt, marker::PhantomData, rc::Rc};
+
+use crate::{
+    ops::{
+        Associativity, BinOp, Commutativity, Identity, Invertible, Multiplication, OperationKind,
+    },
+    structures::{FiniteGroup, Group, Subgroup},
+};
+
+impl<G: Group<Op>, Op: OperationKind> Subgroup<G, Op> {
+    /// The left coset `gH`
+    pub fn left_coset(&self, g: &G) -> Vec<G> {
+        self.elements().iter().map(|h| g.op(h)).collect()
+    }
+
+    /// The right coset `Hg`
+    pub fn right_coset(&self, g: &G) -> Vec<G> {
+        self.elements().iter().map(|h| h.op(g)).collect()
+    }
+}
+
+impl<G: FiniteGroup<Op>, Op: OperationKind> Subgroup<G, Op> {
+    /// All left cosets `gH`, each listed once, starting with `H` itself
+    pub fn left_cosets(&self) -> Vec<Vec<G>> {
+        self.cosets(Self::left_coset)
+    }
+
+    /// All right cosets `Hg`, each listed once, starting with `H` itself
+    pub fn right_cosets(&self) -> Vec<Vec<G>> {
+        self.cosets(Self::right_coset)
+    }
+
+    fn cosets(&self, coset: impl Fn(&Self, &G) -> Vec<G>) -> Vec<Vec<G>> {
+        let mut cosets: Vec<Vec<G>> = Vec::new();
+        for g in G::elements() {
+            if !cosets.iter().any(|c| c.contains(&g)) {
+                cosets.push(coset(self, &g));
+            }
+        }
+        cosets
+    }
+
+    /// The index `[G:H]`, i.e. the number of cosets of `H` in `G`
+    ///
+    /// # Panics
+    /// if the order of `G` does not fit into a `usize`
+    pub fn index(&self) -> usize {
+        G::order() / self.order()
+    }
+
+    /// Whether `gHg^-1 = H` for all `g` in `G`
+    pub fn is_normal(&self) -> bool {
+        G::elements().all(|g| {
+            self.generators()
+                .iter()
+                .all(|h| self.contains(&h.conjugate(&g)))
+        })
+    }
+}
+
+/// A normal subgroup `N` of `G`, described at the type level so it can be used to form the
+/// [`QuotientGroup`] `G/N`.
+///
+/// Implementors should make sure the subgroup is actually normal, which can be verified using
+/// [`Subgroup::is_normal`]. For finite `G` this is checked in debug builds whenever the elements
+/// or the order of the quotient group are computed.
+pub trait NormalSubgroup<G: Group<Op>, Op: OperationKind = Multiplication> {
+    /// Generators of the normal subgroup
+    fn generators() -> Vec<G>;
+
+    /// The normal subgroup generated by [`generators`](Self::generators)
+    fn subgroup() -> Subgroup<G, Op> {
+        Subgroup::generated_by(Self::generators())
+    }
+}
+
+/// An element of the quotient group `G/N`, i.e. a coset `gN` of the normal subgroup `N`.
+///
+/// Two cosets are equal if their representatives differ by an element of `N`. Every element
+/// holds the closure of the generators of `N`, which is shared by all elements derived from it.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::perm;
+/// use abstract_algebra::ops::{BinOp, Identity, Multiplication};
+/// use abstract_algebra::primitives::Permutation;
+/// use abstract_algebra::structures::{FiniteGroup, NormalSubgroup, QuotientGroup};
+///
+/// /// The Klein four-group `V_4`
+/// struct V4;
+/// impl NormalSubgroup<Permutation<4>> for V4 {
+///     fn generators() -> Vec<Permutation<4>> {
+///         vec![perm![(1 2)(3 4)], perm![(1 3)(2 4)]]
+///     }
+/// }
+///
+/// type S4ModV4 = QuotientGroup<Permutation<4>, V4>;
+/// assert_eq!(<S4ModV4 as FiniteGroup<Multiplication>>::order(), 6);
+/// assert_eq!(S4ModV4::new(perm![(1 2)(3 4)]), S4ModV4::id());
+/// assert_eq!(S4ModV4::new(perm![(1 2)]), S4ModV4::new(perm![(3 4)]));
+/// ```
+pub struct QuotientGroup<G, N, Op = Multiplication>
+where
+    G: Group<Op>,
+    N: NormalSubgroup<G, Op>,
+    Op: OperationKind,
+{
+    representative: G,
+    subgroup: Rc<Subgroup<G, Op>>,
+    _marker: PhantomData<N>,
+}
+
+impl<G, N, Op> QuotientGroup<G, N, Op>
+where
+    G: Group<Op>,
+    N: NormalSubgroup<G, Op>,
+    Op: OperationKind,
+{
+    /// The coset `gN`
+    ///
+    /// This computes the closure of the generators of `N`, so cosets should rather be obtained
+    /// from existing ones using the group operations where possible.
+    pub fn new(g: G) -> Self {
+        Self::in_subgroup(g, Rc::new(N::subgroup()))
+    }
+
+    fn in_subgroup(g: G, subgroup: Rc<Subgroup<G, Op>>) -> Self {
+        Self {
+            representative: g,
+            subgroup,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Some element `g` of this coset `gN`
+    pub fn representative(&self) -> &G {
+        &self.representative
+    }
+
+    /// All elements of this coset `gN`
+    pub fn coset(&self) -> Vec<G> {
+        self.subgroup.left_coset(&self.representative)
+    }
+}
+
+impl<G, N, Op> Clone for QuotientGroup<G, N, Op>
+where
+    G: Group<Op>,
+    N: NormalSubgroup<G, Op>,
+    Op: OperationKind,
+{
+    fn clone(&self) -> Self {
+        Self::in_subgroup(self.representative.clone(), self.subgroup.clone())
+    }
+}
+
+impl<G, N, Op> fmt::Debug for QuotientGroup<G, N, Op>
+where
+    G: Group<Op>,
+    N: NormalSubgroup<G, Op>,
+    Op: OperationKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("QuotientGroup")
+            .field(&self.representative)
+            .finish()
+    }
+}
+
+impl<G, N, Op> PartialEq for QuotientGroup<G, N, Op>
+where
+    G: Group<Op>,
+    N: NormalSubgroup<G, Op>,
+    Op: OperationKind,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.subgroup
+            .contains(&self.representative.inv().op(&other.representative))
+    }
+}
+impl<G, N, Op> Eq for QuotientGroup<G, N, Op>
+where
+    G: Group<Op>,
+    N: NormalSubgroup<G, Op>,
+    Op: OperationKind,
+{
+}
+
+impl<G, N, Op> BinOp<Op> for QuotientGroup<G, N, Op>
+where
+    G: Group<Op>,
+    N: NormalSubgroup<G, Op>,
+    Op: OperationKind,
+{
+    fn op(&self, rhs: &Self) -> Self {
+        Self::in_subgroup(
+            self.representative.op(&rhs.representative),
+            self.subgroup.clone(),
+        )
+    }
+}
+
+impl<G, N, Op> Identity<Op> for QuotientGroup<G, N, Op>
+where
+    G: Group<Op>,
+    N: NormalSubgroup<G, Op>,
+    Op: OperationKind,
+{
+    fn id() -> Self {
+        Self::new(G::id())
+    }
+}
+
+impl<G, N, Op> Invertible<Op> for QuotientGroup<G, N, Op>
+where
+    G: Group<Op>,
+    N: NormalSubgroup<G, Op>,
+    Op: OperationKind,
+{
+    fn inv(&self) -> Self {
+        Self::in_subgroup(self.representative.inv(), self.subgroup.clone())
+    }
+}
+
+impl<G, N, Op> Associativity<Op> for QuotientGroup<G, N, Op>
+where
+    G: Group<Op>,
+    N: NormalSubgroup<G, Op>,
+    Op: OperationKind,
+{
+}
+
+impl<G, N, Op> Commutativity<Op> for QuotientGroup<G, N, Op>
+where
+    G: Group<Op> + Commutativity<Op>,
+    N: NormalSubgroup<G, Op>,
+    Op: OperationKind,
+{
+}
+
+impl<G, N, Op> QuotientGroup<G, N, Op>
+where
+    G: FiniteGroup<Op>,
+    N: NormalSubgroup<G, Op>,
+    Op: OperationKind,
+{
+    fn normal_subgroup() -> Subgroup<G, Op> {
+        let subgroup = N::subgroup();
+        debug_assert!(subgroup.is_normal(), "the subgroup has to be normal");
+        subgroup
+    }
+}
+
+impl<G, N, Op> FiniteGroup<Op> for QuotientGroup<G, N, Op>
+where
+    G: FiniteGroup<Op>,
+    N: NormalSubgroup<G, Op>,
+    Op: OperationKind,
+{
+    fn elements() -> impl Iterator<Item = Self> {
+        let subgroup = Rc::new(Self::normal_subgroup());
+        subgroup
+            .left_cosets()
+            .into_iter()
+            .map(move |coset| Self::in_subgroup(coset[0].clone(), subgroup.clone()))
+    }
+    /// # Panics
+    /// if the order of `G` does not fit into a `usize`
+    fn order() -> usize {
+        G::order() / Self::normal_subgroup().order()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::{Addition, Identity, test_finite_group},
+        perm,
+        primitives::{CyclicNumber, Permutation},
+    };
+
+    use super::*;
+
+    struct V4;
+    impl NormalSubgroup<Permutation<4>> for V4 {
+        fn generators() -> Vec<Permutation<4>> {
+            vec![perm![(1 2)(3 4)], perm![(1 3)(2 4)]]
+        }
+    }
+
+    struct Multiples<const K: usize>;
+    impl<const K: usize, const N: usize> NormalSubgroup<CyclicNumber<N>, Addition> for Multiples<K> {
+        fn generators() -> Vec<CyclicNumber<N>> {
+            vec![K.into()]
+        }
+    }
+
+    struct Transposition;
+    impl NormalSubgroup<Permutation<3>> for Transposition {
+        fn generators() -> Vec<Permutation<3>> {
+            vec![perm![(1 2)]]
+        }
+    }
+
+    #[test]
+    fn cosets() {
+        let h: Subgroup<Permutation<3>> = Subgroup::generated_by([perm![(1 2)]]);
+        assert_eq!(h.index(), 3);
+        assert_eq!(h.left_cosets().len(), 3);
+        assert_eq!(h.right_cosets().len(), 3);
+        assert!(!h.is_normal());
+        let g = perm![3; (1 3)];
+        assert!(!h.left_coset(&g).contains(&h.right_coset(&g)[1]));
+
+        let a3: Subgroup<Permutation<3>> = Subgroup::generated_by([perm![(1 2 3)]]);
+        assert!(a3.is_normal());
+        assert!(V4::subgroup().is_normal());
+    }
+
+    #[test]
+    fn quotient_group() {
+        type Q = QuotientGroup<Permutation<4>, V4>;
+        assert_eq!(Q::order(), 6);
+        test_finite_group::<Q, _>();
+        for x in Q::elements() {
+            assert_eq!(x.op(&x.inv()), Q::id());
+        }
+        // S_4 / V_4 is non-abelian
+        let a = Q::new(perm![(1 2)]);
+        let b = Q::new(perm![(1 2 3)]);
+        assert_ne!(a.op(&b), b.op(&a));
+        assert_eq!(a.coset().len(), 4);
+        // The closure of the generators is shared by all derived cosets
+        assert!(Rc::ptr_eq(&a.op(&b).inv().subgroup, &a.subgroup));
+
+        type Z12ModZ4 = QuotientGroup<CyclicNumber<12>, Multiples<4>, Addition>;
+        assert_eq!(Z12ModZ4::order(), 4);
+        assert_eq!(Z12ModZ4::new(5.into()), Z12ModZ4::new(1.into()));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "the subgroup has to be normal"]
+    fn quotient_by_non_normal_subgroup() {
+        QuotientGroup::<Permutation<3>, Transposition>::order();
+    }
+}
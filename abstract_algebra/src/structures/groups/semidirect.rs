@@ -0,0 +1,290 @@
+use std::{fmt, marker::PhantomData};
+
+use crate::{
+    ops::{Addition, Associativity, BinOp, Identity, Invertible, Multiplication, OperationKind},
+    primitives::CyclicNumber,
+    structures::{AbelianGroup, FiniteGroup, Group},
+};
+
+/// An action `φ: H -> Aut(N)` of `H` on `N` by automorphisms, described at the type level so it
+/// can be used to form the [`SemidirectProduct`] `N ⋊ H`.
+pub trait SemidirectAction<N, H, OpN = Multiplication, OpH = Multiplication>
+where
+    N: Group<OpN>,
+    H: Group<OpH>,
+    OpN: OperationKind,
+    OpH: OperationKind,
+{
+    /// The image `φ_h(n)` of `n` under the automorphism `φ_h`
+    fn act(h: &H, n: &N) -> N;
+
+    /// Verifies that every `φ_h` is an automorphism of `N` and that `φ_{h1 h2} = φ_{h1} ∘ φ_{h2}`
+    fn is_action() -> bool
+    where
+        N: FiniteGroup<OpN>,
+        H: FiniteGroup<OpH>,
+    {
+        let ns: Vec<N> = N::elements().collect();
+        let hs: Vec<H> = H::elements().collect();
+        let automorphisms = hs.iter().all(|h| {
+            let images: Vec<N> = ns.iter().map(|n| Self::act(h, n)).collect();
+            let bijective = ns.iter().all(|n| images.contains(n));
+            let homomorphism = ns.iter().enumerate().all(|(i, a)| {
+                ns.iter()
+                    .enumerate()
+                    .all(|(j, b)| Self::act(h, &a.op(b)) == images[i].op(&images[j]))
+            });
+            bijective && homomorphism
+        });
+        let compatible = hs.iter().all(|h1| {
+            hs.iter().all(|h2| {
+                ns.iter()
+                    .all(|n| Self::act(&h1.op(h2), n) == Self::act(h1, &Self::act(h2, n)))
+            })
+        });
+        automorphisms && compatible
+    }
+}
+
+/// The action of `Z/2Z` on an abelian group by inversion, where `1` maps `n` to `n^-1`.
+///
+/// The resulting semidirect product `C_N ⋊ C_2` is the dihedral group `D_N`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Inversion;
+
+impl<N, OpN> SemidirectAction<N, CyclicNumber<2>, OpN, Addition> for Inversion
+where
+    N: AbelianGroup<OpN>,
+    OpN: OperationKind,
+{
+    fn act(h: &CyclicNumber<2>, n: &N) -> N {
+        if *h == Identity::<Addition>::id() {
+            n.clone()
+        } else {
+            n.inv()
+        }
+    }
+}
+
+/// An element `(n, h)` of the semidirect product `N ⋊ H` with respect to the action `A`.
+///
+/// The product is `(n1, h1)(n2, h2) = (n1 φ_{h1}(n2), h1 h2)`.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::ops::{Addition, Multiplication};
+/// use abstract_algebra::primitives::{CyclicNumber, DihedralElement};
+/// use abstract_algebra::structures::{Inversion, SemidirectProduct, is_isomorphic};
+///
+/// type D5 = SemidirectProduct<CyclicNumber<5>, CyclicNumber<2>, Inversion, Addition, Addition>;
+/// assert!(is_isomorphic::<D5, DihedralElement<5>, Multiplication, Multiplication>());
+/// ```
+pub struct SemidirectProduct<N, H, A, OpN = Multiplication, OpH = Multiplication>
+where
+    N: Group<OpN>,
+    H: Group<OpH>,
+    A: SemidirectAction<N, H, OpN, OpH>,
+    OpN: OperationKind,
+    OpH: OperationKind,
+{
+    n: N,
+    h: H,
+    _marker: PhantomData<(A, OpN, OpH)>,
+}
+
+impl<N, H, A, OpN, OpH> SemidirectProduct<N, H, A, OpN, OpH>
+where
+    N: Group<OpN>,
+    H: Group<OpH>,
+    A: SemidirectAction<N, H, OpN, OpH>,
+    OpN: OperationKind,
+    OpH: OperationKind,
+{
+    pub fn new(n: N, h: H) -> Self {
+        Self {
+            n,
+            h,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The components `(n, h)` of the element
+    pub fn parts(&self) -> (&N, &H) {
+        (&self.n, &self.h)
+    }
+}
+
+impl<N, H, A, OpN, OpH> Clone for SemidirectProduct<N, H, A, OpN, OpH>
+where
+    N: Group<OpN>,
+    H: Group<OpH>,
+    A: SemidirectAction<N, H, OpN, OpH>,
+    OpN: OperationKind,
+    OpH: OperationKind,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.n.clone(), self.h.clone())
+    }
+}
+
+impl<N, H, A, OpN, OpH> fmt::Debug for SemidirectProduct<N, H, A, OpN, OpH>
+where
+    N: Group<OpN>,
+    H: Group<OpH>,
+    A: SemidirectAction<N, H, OpN, OpH>,
+    OpN: OperationKind,
+    OpH: OperationKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SemidirectProduct")
+            .field(&self.n)
+            .field(&self.h)
+            .finish()
+    }
+}
+
+impl<N, H, A, OpN, OpH> PartialEq for SemidirectProduct<N, H, A, OpN, OpH>
+where
+    N: Group<OpN>,
+    H: Group<OpH>,
+    A: SemidirectAction<N, H, OpN, OpH>,
+    OpN: OperationKind,
+    OpH: OperationKind,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.n == other.n && self.h == other.h
+    }
+}
+impl<N, H, A, OpN, OpH> Eq for SemidirectProduct<N, H, A, OpN, OpH>
+where
+    N: Group<OpN>,
+    H: Group<OpH>,
+    A: SemidirectAction<N, H, OpN, OpH>,
+    OpN: OperationKind,
+    OpH: OperationKind,
+{
+}
+
+impl<N, H, A, OpN, OpH> BinOp<Multiplication> for SemidirectProduct<N, H, A, OpN, OpH>
+where
+    N: Group<OpN>,
+    H: Group<OpH>,
+    A: SemidirectAction<N, H, OpN, OpH>,
+    OpN: OperationKind,
+    OpH: OperationKind,
+{
+    fn op(&self, rhs: &Self) -> Self {
+        Self::new(self.n.op(&A::act(&self.h, &rhs.n)), self.h.op(&rhs.h))
+    }
+}
+
+impl<N, H, A, OpN, OpH> Identity<Multiplication> for SemidirectProduct<N, H, A, OpN, OpH>
+where
+    N: Group<OpN>,
+    H: Group<OpH>,
+    A: SemidirectAction<N, H, OpN, OpH>,
+    OpN: OperationKind,
+    OpH: OperationKind,
+{
+    fn id() -> Self {
+        Self::new(N::id(), H::id())
+    }
+}
+
+impl<N, H, A, OpN, OpH> Invertible<Multiplication> for SemidirectProduct<N, H, A, OpN, OpH>
+where
+    N: Group<OpN>,
+    H: Group<OpH>,
+    A: SemidirectAction<N, H, OpN, OpH>,
+    OpN: OperationKind,
+    OpH: OperationKind,
+{
+    fn inv(&self) -> Self {
+        let h = self.h.inv();
+        Self::new(A::act(&h, &self.n.inv()), h)
+    }
+}
+
+impl<N, H, A, OpN, OpH> Associativity<Multiplication> for SemidirectProduct<N, H, A, OpN, OpH>
+where
+    N: Group<OpN>,
+    H: Group<OpH>,
+    A: SemidirectAction<N, H, OpN, OpH>,
+    OpN: OperationKind,
+    OpH: OperationKind,
+{
+}
+
+/// Lists the elements with the `N` component changing fastest
+impl<N, H, A, OpN, OpH> FiniteGroup<Multiplication> for SemidirectProduct<N, H, A, OpN, OpH>
+where
+    N: FiniteGroup<OpN>,
+    H: FiniteGroup<OpH>,
+    A: SemidirectAction<N, H, OpN, OpH>,
+    OpN: OperationKind,
+    OpH: OperationKind,
+{
+    fn elements() -> impl Iterator<Item = Self> {
+        H::elements().flat_map(|h| N::elements().map(move |n| Self::new(n, h.clone())))
+    }
+    fn order() -> usize {
+        N::order() * H::order()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        primitives::DihedralElement,
+        structures::{Subgroup, is_isomorphic},
+    };
+
+    use super::*;
+
+    /// `C_3` acting on `C_7` through multiplication by powers of `2`, which has order 3 mod 7
+    struct Doubling;
+    impl SemidirectAction<CyclicNumber<7>, CyclicNumber<3>, Addition, Addition> for Doubling {
+        fn act(h: &CyclicNumber<3>, n: &CyclicNumber<7>) -> CyclicNumber<7> {
+            let k = (0..3).position(|k| *h == CyclicNumber::from(k)).unwrap();
+            Group::<Addition>::powi(n, 2_isize.pow(k as u32))
+        }
+    }
+
+    /// Not an action by automorphisms
+    struct Shift;
+    impl SemidirectAction<CyclicNumber<3>, CyclicNumber<3>, Addition, Addition> for Shift {
+        fn act(h: &CyclicNumber<3>, n: &CyclicNumber<3>) -> CyclicNumber<3> {
+            BinOp::<Addition>::op(n, h)
+        }
+    }
+
+    #[test]
+    fn dihedral_group() {
+        type D4 =
+            SemidirectProduct<CyclicNumber<4>, CyclicNumber<2>, Inversion, Addition, Addition>;
+        assert!(<Inversion as SemidirectAction<
+            CyclicNumber<4>,
+            CyclicNumber<2>,
+            _,
+            _,
+        >>::is_action());
+        assert_eq!(D4::order(), 8);
+        let r = D4::new(1.into(), 0.into());
+        let s = D4::new(0.into(), 1.into());
+        assert_eq!(s.op(&r).op(&s), r.inv());
+        assert!(is_isomorphic::<D4, DihedralElement<4>, _, _>());
+    }
+
+    #[test]
+    fn frobenius_group() {
+        type F21 =
+            SemidirectProduct<CyclicNumber<7>, CyclicNumber<3>, Doubling, Addition, Addition>;
+        assert!(Doubling::is_action());
+        assert!(!Shift::is_action());
+        assert_eq!(F21::order(), 21);
+        assert!(Subgroup::<F21>::center().is_trivial());
+        let x = F21::new(3.into(), 2.into());
+        assert_eq!(x.op(&x.inv()), F21::id());
+        assert_eq!(F21::class_equation(), vec![1, 3, 3, 7, 7]);
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{
+    ops::{BinOp, Identity, Invertible},
+    primitives::Permutation,
+};
+
+/// One level of a [`StabilizerChain`], i.e. the stabilizer `G_k` of the points `1..=k` acting on
+/// the point `k + 1`
+#[derive(Debug, Clone)]
+struct Level<const N: usize> {
+    point: usize,
+    generators: Vec<Permutation<N>>,
+    /// The transversal element mapping `point` to `p` at index `p - 1`, if `p` is in the orbit
+    transversal: Vec<Option<Permutation<N>>>,
+}
+
+impl<const N: usize> Level<N> {
+    fn new(point: usize) -> Self {
+        let mut transversal = vec![None; N];
+        transversal[point - 1] = Some(Permutation::id());
+        Self {
+            point,
+            generators: Vec::new(),
+            transversal,
+        }
+    }
+
+    fn orbit_size(&self) -> usize {
+        self.transversal.iter().flatten().count()
+    }
+}
+
+/// A base and strong generating set of a group of permutations, computed with the
+/// Schreier–Sims algorithm.
+///
+/// The chain consists of the stabilizers `G = G_0 >= G_1 >= ... >= G_N = 1`, where `G_k` fixes
+/// the points `1..=k`, together with transversals of `G_{k+1}` in `G_k`. This gives the order and
+/// membership tests without enumerating the group, which is hopeless for groups like the
+/// Rubik's cube group.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::perm;
+/// use abstract_algebra::ops::{Identity, Invertible};
+/// use abstract_algebra::structures::StabilizerChain;
+///
+/// let m11 = StabilizerChain::<11>::new([
+///     perm![(1 2 3 4 5 6 7 8 9 10 11)],
+///     perm![(3 7 11 8)(4 10 5 6)],
+/// ]);
+/// assert_eq!(m11.order(), 7920);
+/// assert!(m11.contains(&perm![(1 2 3 4 5 6 7 8 9 10 11)].inv()));
+/// assert!(!m11.contains(&perm![(1 2)]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct StabilizerChain<const N: usize> {
+    levels: Vec<Level<N>>,
+}
+
+impl<const N: usize> StabilizerChain<N> {
+    /// Computes the chain of the group generated by `generators` using Knuth's variant of the
+    /// Schreier–Sims algorithm
+    pub fn new(generators: impl IntoIterator<Item = Permutation<N>>) -> Self {
+        let mut chain = Self {
+            levels: (1..=N).map(Level::new).collect(),
+        };
+        for g in generators {
+            if !chain.contains(&g) {
+                chain.add_generator(0, g);
+            }
+        }
+        chain
+    }
+
+    /// Adds `g`, which fixes the points `1..=k`, to the generators of `G_k`
+    fn add_generator(&mut self, k: usize, g: Permutation<N>) {
+        self.levels[k].generators.push(g);
+        let orbit: Vec<Permutation<N>> = self.levels[k]
+            .transversal
+            .iter()
+            .flatten()
+            .copied()
+            .collect();
+        for t in orbit {
+            self.extend_orbit(k, g.op(&t));
+        }
+    }
+
+    /// Records `t`, an element of `G_k`, in the transversal of level `k` if it maps the point of
+    /// the level somewhere new, and otherwise sifts the resulting Schreier generator into `G_{k+1}`.
+    /// New transversal elements are multiplied by the generators of `G_k` in turn, using a
+    /// worklist so that the recursion depth is bounded by the number of levels.
+    fn extend_orbit(&mut self, k: usize, t: Permutation<N>) {
+        let mut pending = vec![t];
+        while let Some(t) = pending.pop() {
+            let level = &self.levels[k];
+            let p = t.image(level.point);
+            match level.transversal[p - 1] {
+                Some(u) => {
+                    let h = u.inv().op(&t);
+                    if !self.sifts_from(k + 1, h) {
+                        self.add_generator(k + 1, h);
+                    }
+                }
+                None => {
+                    let level = &mut self.levels[k];
+                    level.transversal[p - 1] = Some(t);
+                    pending.extend(level.generators.iter().map(|g| g.op(&t)));
+                }
+            }
+        }
+    }
+
+    /// Whether `g`, which fixes the points `1..=k`, is in `G_k`
+    fn sifts_from(&self, k: usize, mut g: Permutation<N>) -> bool {
+        for level in &self.levels[k..] {
+            match level.transversal[g.image(level.point) - 1] {
+                Some(u) => g = u.inv().op(&g),
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// The base points, i.e. the points `k + 1` where `G_k` moves `k + 1`
+    pub fn base(&self) -> Vec<usize> {
+        self.levels
+            .iter()
+            .filter(|level| level.orbit_size() > 1)
+            .map(|level| level.point)
+            .collect()
+    }
+
+    /// A strong generating set, which contains generators for every `G_k`
+    pub fn strong_generators(&self) -> Vec<Permutation<N>> {
+        self.levels
+            .iter()
+            .flat_map(|level| level.generators.iter().copied())
+            .collect()
+    }
+
+    /// The sizes of the orbits of `G_k` on the base points
+    pub fn basic_orbit_sizes(&self) -> Vec<usize> {
+        self.levels
+            .iter()
+            .map(Level::orbit_size)
+            .filter(|&size| size > 1)
+            .collect()
+    }
+
+    /// The order of the group, i.e. the product of the basic orbit sizes
+    pub fn order(&self) -> u128 {
+        self.levels
+            .iter()
+            .map(|level| level.orbit_size() as u128)
+            .product()
+    }
+
+    pub fn contains(&self, g: &Permutation<N>) -> bool {
+        self.sifts_from(0, *g)
+    }
+
+    /// Sifts `g` through the chain, writing it as `g = u_0 u_1 ... u_m r` with transversal
+    /// elements `u_k`, one for every base point, and a residue `r`.
+    ///
+    /// The residue is the identity if and only if `g` is in the group.
+    ///
+    /// # Example
+    /// ```rust
+    /// use abstract_algebra::perm;
+    /// use abstract_algebra::ops::{BinOp, Identity};
+    /// use abstract_algebra::primitives::Permutation;
+    /// use abstract_algebra::structures::StabilizerChain;
+    ///
+    /// let a4 = StabilizerChain::new([perm![4; (1 2 3)], perm![4; (2 3 4)]]);
+    /// let g = perm![4; (1 2)(3 4)];
+    /// let (representatives, residue) = a4.sift(&g);
+    /// assert_eq!(residue, Permutation::id());
+    /// let product = representatives.iter().fold(Permutation::id(), |x, u| x.op(u));
+    /// assert_eq!(product, g);
+    /// ```
+    pub fn sift(&self, g: &Permutation<N>) -> (Vec<Permutation<N>>, Permutation<N>) {
+        let mut representatives = Vec::new();
+        let mut g = *g;
+        for level in self.levels.iter().filter(|level| level.orbit_size() > 1) {
+            match level.transversal[g.image(level.point) - 1] {
+                Some(u) => {
+                    representatives.push(u);
+                    g = u.inv().op(&g);
+                }
+                None => break,
+            }
+        }
+        (representatives, g)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        perm,
+        structures::{FiniteGroup, Subgroup},
+    };
+
+    use super::*;
+
+    #[test]
+    fn agrees_with_enumeration() {
+        let generators = [perm![6; (1 2 3)(4 5)], perm![6; (1 4)(2 6)]];
+        let chain = StabilizerChain::new(generators);
+        let subgroup = Subgroup::generated_by(generators);
+        assert_eq!(chain.order(), subgroup.order() as u128);
+        for g in Permutation::<6>::elements() {
+            assert_eq!(chain.contains(&g), subgroup.contains(&g));
+        }
+        for g in chain.strong_generators() {
+            assert!(subgroup.contains(&g));
+        }
+        assert_eq!(
+            chain.basic_orbit_sizes().iter().product::<usize>(),
+            subgroup.order()
+        );
+
+        let trivial = StabilizerChain::<5>::new([Permutation::id()]);
+        assert_eq!(trivial.order(), 1);
+        assert!(trivial.base().is_empty());
+        assert_eq!(
+            StabilizerChain::<7>::new([perm![(1 2 3 4 5 6 7)], perm![(1 2)]]).order(),
+            5040
+        );
+    }
+
+    #[test]
+    fn rubiks_cube() {
+        let moves: [Permutation<48>; 6] = [
+            perm![(1 3 8 6)(2 5 7 4)(9 33 25 17)(10 34 26 18)(11 35 27 19)],
+            perm![(9 11 16 14)(10 13 15 12)(1 17 41 40)(4 20 44 37)(6 22 46 35)],
+            perm![(17 19 24 22)(18 21 23 20)(6 25 43 16)(7 28 42 13)(8 30 41 11)],
+            perm![(25 27 32 30)(26 29 31 28)(3 38 43 19)(5 36 45 21)(8 33 48 24)],
+            perm![(33 35 40 38)(34 37 39 36)(3 9 46 32)(2 12 47 29)(1 14 48 27)],
+            perm![(41 43 48 46)(42 45 47 44)(14 22 30 38)(15 23 31 39)(16 24 32 40)],
+        ];
+        let cube = StabilizerChain::new(moves);
+        assert_eq!(cube.order(), 43_252_003_274_489_856_000);
+        let scramble = moves.iter().fold(Permutation::id(), |x, m| x.op(m));
+        assert!(cube.contains(&scramble));
+        // Twisting a single corner is impossible
+        assert!(!cube.contains(&perm![(1 9 35)]));
+        // Swapping two edges is impossible
+        assert!(!cube.contains(&perm![(2 34)]));
+        let (_, residue) = cube.sift(&perm![(2 34)]);
+        assert_ne!(residue, Permutation::id());
+    }
+}
@@ -0,0 +1,153 @@
+use std::marker::PhantomData;
+
+use crate::{
+    ops::{Multiplication, OperationKind},
+    structures::{FiniteGroup, Group},
+    utils::orbit,
+};
+
+/// A finite subgroup of `G`, stored as the list of its elements together with the generators it
+/// was created from.
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::perm;
+/// use abstract_algebra::ops::Identity;
+/// use abstract_algebra::primitives::Permutation;
+/// use abstract_algebra::structures::Subgroup;
+///
+/// // The Klein four-group inside S_4
+/// let v4: Subgroup<Permutation<4>> = Subgroup::generated_by([perm![(1 2)(3 4)], perm![(1 3)(2 4)]]);
+/// assert_eq!(v4.order(), 4);
+/// assert!(v4.contains(&perm![(1 4)(2 3)]));
+/// assert!(!v4.contains(&perm![(1 2)]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Subgroup<G: Group<Op>, Op: OperationKind = Multiplication> {
+    generators: Vec<G>,
+    elements: Vec<G>,
+    _op: PhantomData<Op>,
+}
+
+impl<G: Group<Op>, Op: OperationKind> Subgroup<G, Op> {
+    /// Computes the subgroup generated by `generators`.
+    ///
+    /// The elements are listed in breadth-first order starting with the identity.
+    ///
+    /// # Note
+    /// This never terminates if the generated subgroup is infinite, e.g. for any non-trivial
+    /// [`Word`](crate::primitives::Word).
+    pub fn generated_by(generators: impl IntoIterator<Item = G>) -> Self {
+        let generators: Vec<G> = generators.into_iter().collect();
+        let (elements, _) = orbit(G::id(), generators.len(), |k, x| x.op(&generators[k]));
+        Self {
+            generators,
+            elements,
+            _op: PhantomData,
+        }
+    }
+
+    /// The subgroup generated by `elements`, using a small generating set picked greedily among
+    /// them. Mostly useful if `elements` is already known to be a subgroup.
+    pub(crate) fn from_elements(elements: impl IntoIterator<Item = G>) -> Self {
+        let mut subgroup = Self::trivial();
+        for x in elements {
+            if !subgroup.contains(&x) {
+                let mut generators = subgroup.generators;
+                generators.push(x);
+                subgroup = Self::generated_by(generators);
+            }
+        }
+        subgroup
+    }
+
+    /// The subgroup only containing the identity
+    pub fn trivial() -> Self {
+        Self::generated_by([])
+    }
+
+    /// The generators this subgroup was created from
+    pub fn generators(&self) -> &[G] {
+        &self.generators
+    }
+
+    /// All elements of the subgroup, starting with the identity
+    pub fn elements(&self) -> &[G] {
+        &self.elements
+    }
+
+    /// The number of elements of the subgroup
+    pub fn order(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn contains(&self, x: &G) -> bool {
+        self.elements.contains(x)
+    }
+
+    /// Whether every element of `self` is contained in `other`
+    pub fn is_subgroup_of(&self, other: &Self) -> bool {
+        self.elements.iter().all(|x| other.contains(x))
+    }
+
+    pub fn is_trivial(&self) -> bool {
+        self.order() == 1
+    }
+}
+
+impl<G: FiniteGroup<Op>, Op: OperationKind> Subgroup<G, Op> {
+    /// The whole group `G` as a subgroup of itself, generated by all of its elements
+    pub fn whole() -> Self {
+        let elements: Vec<G> = G::elements().collect();
+        Self {
+            generators: elements.clone(),
+            elements,
+            _op: PhantomData,
+        }
+    }
+}
+
+/// Two subgroups are equal if they contain the same elements, regardless of their generators
+impl<G: Group<Op>, Op: OperationKind> PartialEq for Subgroup<G, Op> {
+    fn eq(&self, other: &Self) -> bool {
+        self.order() == other.order() && self.is_subgroup_of(other)
+    }
+}
+impl<G: Group<Op>, Op: OperationKind> Eq for Subgroup<G, Op> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::{Addition, Identity},
+        perm,
+        primitives::{CyclicNumber, DihedralElement, Permutation},
+        structures::FiniteGroup,
+    };
+
+    use super::*;
+
+    #[test]
+    fn generated_subgroups() {
+        let h: Subgroup<CyclicNumber<12>, Addition> = Subgroup::generated_by([8.into()]);
+        assert_eq!(h.order(), 3);
+        assert!(h.contains(&4.into()));
+        let k: Subgroup<CyclicNumber<12>, Addition> = Subgroup::generated_by([4.into(), 6.into()]);
+        assert_eq!(k.order(), 6);
+        assert!(h.is_subgroup_of(&k));
+        assert!(!k.is_subgroup_of(&h));
+
+        let r = DihedralElement::<5>::new(1, false);
+        let s = DihedralElement::<5>::new(0, true);
+        let d5: Subgroup<_> = Subgroup::generated_by([r, s]);
+        assert_eq!(d5, Subgroup::whole());
+        assert_eq!(Subgroup::<DihedralElement<5>>::generated_by([r]).order(), 5);
+    }
+
+    #[test]
+    fn symmetric_group() {
+        let s4: Subgroup<Permutation<4>> = Subgroup::generated_by([perm![(1 2)], perm![(1 2 3 4)]]);
+        assert_eq!(s4.order(), Permutation::<4>::order());
+        assert_eq!(s4.generators().len(), 2);
+        assert!(Subgroup::<Permutation<4>>::trivial().is_trivial());
+    }
+}
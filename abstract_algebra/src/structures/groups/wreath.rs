@@ -0,0 +1,136 @@
+use crate::{
+    ops::{Identity, Invertible, Multiplication, OperationKind},
+    primitives::Permutation,
+    structures::{Group, SemidirectAction, SemidirectProduct, Subgroup},
+};
+
+/// The action of `S_K` on `K`-tuples by permuting the coordinates, such that the entry at
+/// position `i` is moved to position `h(i)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoordinatePermutation;
+
+impl<G, Op, const K: usize> SemidirectAction<[G; K], Permutation<K>, Op, Multiplication>
+    for CoordinatePermutation
+where
+    G: Group<Op>,
+    Op: OperationKind,
+{
+    fn act(h: &Permutation<K>, n: &[G; K]) -> [G; K] {
+        let h = h.inv();
+        core::array::from_fn(|j| n[h.image(j + 1) - 1].clone())
+    }
+}
+
+/// An element `(f_1, ..., f_K; h)` of the wreath product `G ≀ S_K`, i.e. the semidirect product
+/// `G^K ⋊ S_K` where `S_K` permutes the coordinates.
+///
+/// The wreath product `G ≀ H` for a subgroup `H` of `S_K` is available through
+/// [`WreathProduct::subgroup`].
+///
+/// # Example
+/// ```rust
+/// use abstract_algebra::perm;
+/// use abstract_algebra::ops::Identity;
+/// use abstract_algebra::primitives::Permutation;
+/// use abstract_algebra::structures::{Subgroup, WreathProduct};
+///
+/// // The hyperoctahedral group of symmetries of the 3-cube
+/// let b3 = WreathProduct::<Permutation<2>, 3>::subgroup(
+///     &Subgroup::whole(),
+///     &Subgroup::generated_by([perm![(1 2)], perm![(1 2 3)]]),
+/// );
+/// assert_eq!(b3.order(), 48);
+/// let as_permutations = Subgroup::<Permutation<6>>::generated_by(
+///     b3.generators().iter().map(|x| x.to_permutation::<6>()),
+/// );
+/// assert_eq!(as_permutations.order(), 48);
+/// ```
+pub type WreathProduct<G, const K: usize, Op = Multiplication> =
+    SemidirectProduct<[G; K], Permutation<K>, CoordinatePermutation, Op, Multiplication>;
+
+impl<G, Op, const K: usize> WreathProduct<G, K, Op>
+where
+    G: Group<Op>,
+    Op: OperationKind,
+{
+    /// The wreath product `B ≀ T` of a subgroup `B` of `G` and a subgroup `T` of `S_K`
+    ///
+    /// # Note
+    /// See [`Subgroup::generated_by`] for infinite `B`
+    pub fn subgroup(base: &Subgroup<G, Op>, top: &Subgroup<Permutation<K>>) -> Subgroup<Self> {
+        let base_generators = (0..K).flat_map(|i| {
+            base.generators().iter().map(move |g| {
+                let f = core::array::from_fn(|j| if i == j { g.clone() } else { G::id() });
+                Self::new(f, Permutation::id())
+            })
+        });
+        let top_generators = top
+            .generators()
+            .iter()
+            .map(|h| Self::new(core::array::from_fn(|_| G::id()), *h));
+        Subgroup::generated_by(base_generators.chain(top_generators))
+    }
+}
+
+impl<const N: usize, const K: usize> WreathProduct<Permutation<N>, K> {
+    /// The imprimitive action on `M = N * K` points, split into `K` blocks of `N` consecutive
+    /// points. `(f_1, ..., f_K; h)` moves the `b`-th point of block `i` to the `f_{h(i)}(b)`-th
+    /// point of block `h(i)`.
+    pub fn to_permutation<const M: usize>(&self) -> Permutation<M> {
+        const { assert!(M == N * K, "M has to be N * K") }
+        let (f, h) = self.parts();
+        let mut images = [0; M];
+        for i in 1..=K {
+            let j = h.image(i);
+            for b in 1..=N {
+                images[(i - 1) * N + b - 1] = (j - 1) * N + f[j - 1].image(b);
+            }
+        }
+        Permutation::new_unchecked(images)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ops::{Addition, BinOp, test_distinct},
+        perm,
+        primitives::{CyclicNumber, DihedralElement},
+        structures::{FiniteGroup, is_isomorphic},
+    };
+
+    use super::*;
+
+    #[test]
+    fn embedding_into_symmetric_group() {
+        type W = WreathProduct<Permutation<2>, 3>;
+        let elements: Vec<W> = W::elements().collect();
+        assert_eq!(elements.len(), 48);
+        for x in &elements {
+            for y in &elements {
+                assert_eq!(
+                    x.op(y).to_permutation::<6>(),
+                    x.to_permutation::<6>().op(&y.to_permutation::<6>())
+                );
+            }
+        }
+        let images: Vec<Permutation<6>> = elements.iter().map(|x| x.to_permutation()).collect();
+        test_distinct(&images);
+    }
+
+    #[test]
+    fn wreath_products() {
+        assert!(is_isomorphic::<
+            WreathProduct<Permutation<2>, 2>,
+            DihedralElement<4>,
+            _,
+            _,
+        >());
+        // C_3 ≀ A_3 as a subgroup of C_3 ≀ S_3
+        let w = WreathProduct::<CyclicNumber<3>, 3, Addition>::subgroup(
+            &Subgroup::whole(),
+            &Subgroup::generated_by([perm![(1 2 3)]]),
+        );
+        assert_eq!(w.order(), 81);
+    }
+}
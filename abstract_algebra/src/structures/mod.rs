@@ -1,12 +1,42 @@
 mod fields;
+mod groups;
 mod rings;
 
 pub use fields::*;
+pub use groups::*;
 pub use rings::*;
 
 use crate::ops::{Associativity, BinOp, Commutativity, Identity, Invertible, OperationKind};
 
-pub trait Monoid<Op: OperationKind>: BinOp<Op> + Associativity<Op> + Identity<Op> {}
+pub trait Monoid<Op: OperationKind>: BinOp<Op> + Associativity<Op> + Identity<Op> {
+    /// Computes `self^n` using square-and-multiply, i.e. with `O(log n)` operations
+    ///
+    /// Negative exponents are handled by [`Group::powi`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use abstract_algebra::perm;
+    /// use abstract_algebra::ops::Identity;
+    /// use abstract_algebra::structures::Monoid;
+    ///
+    /// let a = perm![4; (1 2 3 4)];
+    /// assert_eq!(a.pow(2), perm![(1 3)(2 4)]);
+    /// ```
+    fn pow(&self, mut n: usize) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::id();
+        while n > 0 {
+            if n % 2 == 1 {
+                result = result.op(&base);
+            }
+            n /= 2;
+            if n > 0 {
+                base = base.op(&base);
+            }
+        }
+        result
+    }
+}
 impl<Op, T> Monoid<Op> for T
 where
     Op: OperationKind,
@@ -15,11 +45,63 @@ where
 }
 
 pub trait Group<Op: OperationKind>: Monoid<Op> + Invertible<Op> {
-    fn pow(&self, n: usize) -> Self {
-        match n {
-            0 => Self::id(),
-            _ => self.pow(n - 1).op(&self),
+    /// Computes `self^n` for a signed exponent, where negative exponents are powers of the inverse
+    ///
+    /// Signed powers are spelled `a.powi(-3)` rather than `a.pow(-3)`, since [`Monoid::pow`]
+    /// takes an unsigned exponent and is available for every monoid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use abstract_algebra::perm;
+    /// use abstract_algebra::ops::{Identity, Invertible};
+    /// use abstract_algebra::structures::Group;
+    ///
+    /// let a = perm![4; (1 2 3 4)];
+    /// assert_eq!(a.powi(-3), a);
+    /// assert_eq!(a.powi(-1), a.inv());
+    /// assert_eq!(a.powi(1_000_000_000_000_000_000), Identity::id());
+    /// ```
+    fn powi(&self, n: isize) -> Self {
+        if n < 0 {
+            self.inv().pow(n.unsigned_abs())
+        } else {
+            self.pow(n.unsigned_abs())
+        }
+    }
+
+    /// The smallest `k` with `1 <= k <= bound` such that `self^k` is the identity, or `None` if
+    /// there is no such `k` (e.g. if `self` has infinite order)
+    ///
+    /// # Example
+    /// ```rust
+    /// use abstract_algebra::ops::Addition;
+    /// use abstract_algebra::primitives::Integer;
+    /// use abstract_algebra::structures::Group;
+    ///
+    /// assert_eq!(Group::<Addition>::order_bounded(&Integer::new(0), 100), Some(1));
+    /// assert_eq!(Group::<Addition>::order_bounded(&Integer::new(3), 100), None);
+    /// ```
+    fn order_bounded(&self, bound: usize) -> Option<usize> {
+        let id = Self::id();
+        let mut x = self.clone();
+        for k in 1..=bound {
+            if x == id {
+                return Some(k);
+            }
+            x = x.op(self);
         }
+        None
+    }
+
+    /// Conjugates `self` by `g`, i.e. computes `g * self * g^-1`
+    fn conjugate(&self, g: &Self) -> Self {
+        g.op(self).op(&g.inv())
+    }
+
+    /// The commutator `[self, g] = self * g * self^-1 * g^-1`, which is the identity if and only
+    /// if `self` and `g` commute
+    fn commutator(&self, g: &Self) -> Self {
+        self.op(g).op(&self.inv()).op(&g.inv())
     }
 }
 impl<Op, T> Group<Op> for T
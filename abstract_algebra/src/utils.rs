@@ -11,3 +11,46 @@ pub const fn is_prime(n: usize) -> bool {
     }
     true
 }
+
+pub const fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub const fn lcm(a: usize, b: usize) -> usize {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / gcd(a, b) * b
+}
+
+/// Breadth-first computes the orbit of `root` under the maps `act(k, ·)` for `k` in
+/// `0..generators`, together with the Schreier tree, whose edges are `(k, parent)` indices
+/// such that `points[i] = act(k, &points[parent])`, or `None` for the root.
+///
+/// This is the closure computed for everything given by generators. In a finite group every
+/// inverse is a power, so applying the generators suffices. The flip side is that it never
+/// terminates on infinite orbits, which is documented publicly on
+/// [`Subgroup::generated_by`](crate::structures::Subgroup::generated_by).
+pub(crate) fn orbit<X: PartialEq>(
+    root: X,
+    generators: usize,
+    act: impl Fn(usize, &X) -> X,
+) -> (Vec<X>, Vec<Option<(usize, usize)>>) {
+    let mut points = vec![root];
+    let mut edges = vec![None];
+    let mut i = 0;
+    while i < points.len() {
+        for k in 0..generators {
+            let y = act(k, &points[i]);
+            if !points.contains(&y) {
+                points.push(y);
+                edges.push(Some((k, i)));
+            }
+        }
+        i += 1;
+    }
+    (points, edges)
+}